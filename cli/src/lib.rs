@@ -13,11 +13,28 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
+use anchor_client::anchor_lang::{Id, InstructionData, ToAccountMetas};
+use anchor_client::solana_sdk::hash::hash;
+use anchor_client::solana_sdk::instruction::{AccountMeta, Instruction};
+use anchor_client::solana_sdk::message::Message;
+use anchor_client::solana_sdk::packet::PACKET_DATA_SIZE;
 use anchor_client::solana_sdk::pubkey::Pubkey;
-use anchor_client::solana_sdk::system_program;
+use anchor_client::solana_sdk::signature::{Keypair, Signature, Signer};
+use anchor_client::solana_sdk::{bpf_loader_upgradeable, system_program, sysvar};
+use anchor_client::Program;
+use anchor_spl::metadata::{Metadata, MetadataAccount};
+use anchor_spl::token::TokenAccount;
 use anyhow::Result;
 use clap::{Parser, Subcommand, ValueEnum};
-use spl_associated_token_account::get_associated_token_address;
+use spl_associated_token_account::{
+    get_associated_token_address, get_associated_token_address_with_program_id,
+};
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::str::FromStr;
+use xnft::merkle::MerkleTree;
+use xnft::state::{to_hex, InstallGate, InstallVoucher};
+use xnft::vouchers;
 
 mod config;
 mod util;
@@ -37,11 +54,40 @@ pub struct Cli {
 #[derive(Clone, ValueEnum)]
 enum AccountType {
     Access,
+    AllowlistClaim,
+    Category,
+    Curator,
+    Delegate,
+    Escrow,
     Install,
+    ProtocolConfig,
+    Release,
     Review,
+    ReviewReply,
+    UpdateProposal,
+    VoucherNonce,
     Xnft,
 }
 
+#[derive(Clone, ValueEnum)]
+enum Permission {
+    ChangePrice,
+    ManageAccess,
+    Suspend,
+    Update,
+}
+
+impl Permission {
+    fn flag(&self) -> u8 {
+        match self {
+            Self::ChangePrice => xnft::PERMISSION_CHANGE_PRICE,
+            Self::ManageAccess => xnft::PERMISSION_MANAGE_ACCESS,
+            Self::Suspend => xnft::PERMISSION_SUSPEND,
+            Self::Update => xnft::PERMISSION_UPDATE,
+        }
+    }
+}
+
 #[derive(Clone, ValueEnum)]
 enum AccessManagementOperation {
     Grant,
@@ -62,28 +108,160 @@ enum Command {
         #[arg(long)]
         json: bool,
     },
+    /// Print the merkle proof of a wallet's inclusion in an allowlist file
+    AllowlistProof {
+        /// The public key of the allowlisted wallet
+        #[arg(value_parser)]
+        wallet: Pubkey,
+        /// Path to a file of allowlisted wallet public keys, one per line
+        #[arg(short, long)]
+        wallets: PathBuf,
+    },
+    /// List the registered xNFT categories
+    Categories {
+        /// Display the categories as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Grant a wallet permissions to manage an xNFT, replacing any it already has
+    Delegate {
+        /// The public key of the wallet being delegated to
+        #[arg(value_parser)]
+        wallet: Pubkey,
+        /// The public key of the xNFT being managed
+        #[arg(short, long, value_parser)]
+        xnft: Pubkey,
+        /// The permissions granted to the wallet
+        #[arg(short, long, value_enum, num_args = 1.., required = true)]
+        permissions: Vec<Permission>,
+    },
     /// Delete an xNFT and close the associated accounts.
     Delete {
         #[arg(value_parser)]
         address: Pubkey,
     },
+    /// Initialize the protocol config as the upgrade authority of the program
+    InitConfig {
+        /// The public key of the account receiving protocol fees
+        #[arg(value_parser)]
+        treasury: Pubkey,
+        /// The protocol fee taken from install payments and donations
+        #[arg(short, long, default_value_t = 0)]
+        fee_basis_points: u16,
+    },
     /// Creates an installation of an xNFT for the wallet
     Install {
         /// The address of the xNFT to be installed
         #[arg(value_parser)]
         address: Pubkey,
+        /// The mint of a held NFT in the collection that gates the xNFT
+        #[arg(long, value_parser)]
+        gate_mint: Option<Pubkey>,
     },
-    /// Grant or revoke access to a wallet for a private xNFT
-    ManageAccess {
-        /// The public key of the target wallet
+    /// Sign a voucher as the install authority for a wallet to install a private xNFT
+    IssueVoucher {
+        /// The public key of the wallet the voucher is issued to
         #[arg(value_parser)]
         wallet: Pubkey,
+        /// The public key of the private xNFT
+        #[arg(short, long, value_parser)]
+        xnft: Pubkey,
+        /// The unix timestamp after which the voucher can no longer be redeemed
+        #[arg(long)]
+        expires_ts: i64,
+        /// The unique number of the voucher for the xNFT
+        #[arg(long)]
+        nonce: u64,
+    },
+    /// Grant or revoke access to a wallet, or a file of wallets, for a private xNFT
+    ManageAccess {
+        /// The public key of the target wallet
+        #[arg(value_parser, required_unless_present = "wallets")]
+        wallet: Option<Pubkey>,
+        /// Path to a file of target wallet public keys, one per line
+        #[arg(short, long, conflicts_with = "wallet")]
+        wallets: Option<PathBuf>,
         /// Either grant or revoke for what action is desired
         #[arg(short, long, value_enum)]
         operation: AccessManagementOperation,
         /// The public key of the private xNFT
         #[arg(short, long, value_parser)]
         xnft: Pubkey,
+        /// The unix timestamp after which a granted access can no longer be used
+        #[arg(long)]
+        expires_ts: Option<i64>,
+        /// The number of installs a granted access can be used for
+        #[arg(long)]
+        max_installs: Option<u32>,
+    },
+    /// Reallocate an xNFT account created with a previous layout to the current size
+    Migrate {
+        /// The public key of the xNFT being migrated
+        #[arg(value_parser)]
+        xnft: Pubkey,
+    },
+    /// Suspend an xNFT, or lift its suspension, as its verified curator or the protocol admin
+    Moderate {
+        /// The public key of the target xNFT
+        #[arg(value_parser)]
+        xnft: Pubkey,
+        /// Act as the protocol admin instead of the xNFT's verified curator
+        #[arg(long)]
+        admin: bool,
+        /// The reason code recorded with the suspension
+        #[arg(short, long, default_value_t = 0)]
+        reason: u8,
+        /// Lift the suspension instead of placing one
+        #[arg(long)]
+        unsuspend: bool,
+    },
+    /// Install a private xNFT with a voucher signed by its install authority
+    RedeemVoucher {
+        /// The public key of the private xNFT
+        #[arg(value_parser)]
+        xnft: Pubkey,
+        /// The unix timestamp after which the voucher can no longer be redeemed
+        #[arg(long)]
+        expires_ts: i64,
+        /// The unique number of the voucher for the xNFT
+        #[arg(long)]
+        nonce: u64,
+        /// The install authority's signature of the voucher
+        #[arg(short, long, value_parser)]
+        signature: Signature,
+    },
+    /// Reclaims the escrowed payment of the wallet's installation of an xNFT
+    Refund {
+        /// The address of the installed xNFT
+        #[arg(value_parser)]
+        address: Pubkey,
+    },
+    /// Pays for another subscription period of the wallet's installation of an xNFT
+    Renew {
+        /// The address of the installed xNFT
+        #[arg(value_parser)]
+        address: Pubkey,
+    },
+    /// Remove a wallet's installation of an xNFT as its install authority or owner
+    RevokeInstall {
+        /// The public key of the wallet that owns the installation
+        #[arg(value_parser)]
+        wallet: Pubkey,
+        /// The public key of the installed xNFT
+        #[arg(short, long, value_parser)]
+        xnft: Pubkey,
+    },
+    /// Set the allowlist of a private xNFT from a file of wallets, or clear it
+    SetAllowlist {
+        /// The public key of the private xNFT
+        #[arg(value_parser)]
+        xnft: Pubkey,
+        /// Path to a file of allowlisted wallet public keys, one per line
+        #[arg(short, long, required_unless_present = "clear")]
+        wallets: Option<PathBuf>,
+        /// Remove the allowlist from the xNFT
+        #[arg(long, conflicts_with = "wallets")]
+        clear: bool,
     },
     /// Assign a curation account to the xNFT
     SetCurator {
@@ -94,11 +272,38 @@ enum Command {
         #[arg(short, long, value_parser)]
         curator: Pubkey,
     },
+    /// Require installers of an xNFT to hold a collection NFT or an amount of tokens, or clear it
+    SetInstallGate {
+        /// The public key of the target xNFT
+        #[arg(value_parser)]
+        xnft: Pubkey,
+        /// The verified collection that installers must hold an NFT from
+        #[arg(long, value_parser, required_unless_present_any = ["token", "clear"])]
+        collection: Option<Pubkey>,
+        /// The SPL token mint that installers must hold an amount of
+        #[arg(long, value_parser, conflicts_with = "collection", requires = "amount")]
+        token: Option<Pubkey>,
+        /// The minimum amount of the token mint to hold
+        #[arg(long, requires = "token")]
+        amount: Option<u64>,
+        /// Remove the install gate from the xNFT
+        #[arg(long, conflicts_with_all = ["collection", "token"])]
+        clear: bool,
+    },
+    /// Release an escrowed install payment after its refund window has passed
+    Settle {
+        /// The public key of the escrow program account
+        #[arg(value_parser)]
+        escrow: Pubkey,
+    },
     /// Toggle the target xNFT's suspended state
     ToggleSuspended {
         /// The public key of the target xNFT
         #[arg(value_parser)]
         xnft: Pubkey,
+        /// The reason code recorded when the xNFT is suspended
+        #[arg(short, long, default_value_t = 0)]
+        reason: u8,
     },
     /// Transfer ownership of an xNFT to another wallet
     Transfer {
@@ -109,6 +314,15 @@ enum Command {
         #[arg(short, long, value_parser)]
         recipient: Pubkey,
     },
+    /// Remove the permissions delegated to a wallet for an xNFT
+    Undelegate {
+        /// The public key of the delegated wallet
+        #[arg(value_parser)]
+        wallet: Pubkey,
+        /// The public key of the managed xNFT
+        #[arg(short, long, value_parser)]
+        xnft: Pubkey,
+    },
     /// Uninstall an xNFT from your wallet
     Uninstall {
         /// The public key of the xNFT to uninstall
@@ -127,6 +341,15 @@ enum Command {
         #[arg(value_parser)]
         xnft: Pubkey,
     },
+    /// Check the content at an xNFT's URI, or a local file, against its content hash
+    VerifyContent {
+        /// The public key of the xNFT being checked
+        #[arg(value_parser)]
+        xnft: Pubkey,
+        /// An http(s) URL or file path to read instead of the xNFT's URI
+        #[arg(short, long)]
+        source: Option<String>,
+    },
 }
 
 pub fn run(args: Cli) -> Result<()> {
@@ -138,22 +361,171 @@ pub fn run(args: Cli) -> Result<()> {
             account_type,
             json,
         } => process_get_account(cfg, account_type, address, json),
+        Command::AllowlistProof { wallet, wallets } => process_allowlist_proof(wallet, wallets),
+        Command::Categories { json } => process_categories(cfg, json),
+        Command::Delegate {
+            wallet,
+            xnft,
+            permissions,
+        } => process_delegate(cfg, wallet, xnft, permissions),
         Command::Delete { address } => process_delete(cfg, address),
-        Command::Install { address } => process_install(cfg, address),
+        Command::InitConfig {
+            treasury,
+            fee_basis_points,
+        } => process_init_config(cfg, treasury, fee_basis_points),
+        Command::Install { address, gate_mint } => process_install(cfg, address, gate_mint),
+        Command::IssueVoucher {
+            wallet,
+            xnft,
+            expires_ts,
+            nonce,
+        } => process_issue_voucher(cfg, wallet, xnft, expires_ts, nonce),
         Command::ManageAccess {
             wallet,
+            wallets,
             operation,
             xnft,
-        } => process_grant_access(cfg, wallet, operation, xnft),
+            expires_ts,
+            max_installs,
+        } => match (wallet, wallets) {
+            (Some(wallet), _) => {
+                process_grant_access(cfg, wallet, operation, xnft, expires_ts, max_installs)
+            }
+            (None, Some(path)) => process_grant_access_batch(
+                cfg,
+                read_wallets(&path)?,
+                operation,
+                xnft,
+                expires_ts,
+                max_installs,
+            ),
+            (None, None) => unreachable!(),
+        },
+        Command::Migrate { xnft } => process_migrate(cfg, xnft),
+        Command::Moderate {
+            xnft,
+            admin,
+            reason,
+            unsuspend,
+        } => process_moderate(cfg, xnft, admin, reason, unsuspend),
+        Command::RedeemVoucher {
+            xnft,
+            expires_ts,
+            nonce,
+            signature,
+        } => process_redeem_voucher(cfg, xnft, expires_ts, nonce, signature),
+        Command::Refund { address } => process_refund(cfg, address),
+        Command::Renew { address } => process_renew(cfg, address),
+        Command::RevokeInstall { wallet, xnft } => process_revoke_install(cfg, wallet, xnft),
+        Command::SetAllowlist {
+            xnft,
+            wallets,
+            clear: _,
+        } => process_set_allowlist(cfg, xnft, wallets),
         Command::SetCurator { xnft, curator } => process_set_curator(cfg, xnft, curator),
-        Command::ToggleSuspended { xnft } => process_toggle_suspend(cfg, xnft),
+        Command::SetInstallGate {
+            xnft,
+            collection,
+            token,
+            amount,
+            clear: _,
+        } => process_set_install_gate(cfg, xnft, collection, token.zip(amount)),
+        Command::Settle { escrow } => process_settle(cfg, escrow),
+        Command::ToggleSuspended { xnft, reason } => process_toggle_suspend(cfg, xnft, reason),
         Command::Transfer { xnft, recipient } => process_transfer(cfg, xnft, recipient),
+        Command::Undelegate { wallet, xnft } => process_undelegate(cfg, wallet, xnft),
         Command::Uninstall { xnft } => process_uninstall(cfg, xnft),
         Command::Unverify { xnft } => process_unverify(cfg, xnft),
         Command::Verify { xnft } => process_verify(cfg, xnft),
+        Command::VerifyContent { xnft, source } => process_verify_content(cfg, xnft, source),
     }
 }
 
+fn process_allowlist_proof(wallet: Pubkey, wallets: PathBuf) -> Result<()> {
+    let tree = MerkleTree::new(&read_wallets(&wallets)?);
+    let proof = tree
+        .proof(&wallet)
+        .ok_or_else(|| anyhow::anyhow!("Wallet {wallet} is not in the allowlist"))?;
+
+    println!("Root: {}", to_hex(tree.root().unwrap()));
+    println!("Proof:");
+    for node in proof {
+        println!("  {}", to_hex(node));
+    }
+    Ok(())
+}
+
+fn process_categories(cfg: Config, json: bool) -> Result<()> {
+    let (program, _) = create_program_client(&cfg);
+    let mut categories: Vec<xnft::state::Category> = program
+        .accounts(vec![])?
+        .into_iter()
+        .map(|(_, c)| c)
+        .collect();
+    categories.sort_by_key(|c| c.id);
+
+    if json {
+        println!("{}", serde_json::to_string(&categories)?);
+    } else {
+        for c in categories {
+            println!("{:>2}  {}", c.id, c.name);
+        }
+    }
+    Ok(())
+}
+
+fn process_delegate(
+    cfg: Config,
+    wallet: Pubkey,
+    address: Pubkey,
+    permissions: Vec<Permission>,
+) -> Result<()> {
+    let (program, signer) = create_program_client(&cfg);
+    let account: xnft::state::Xnft = program.account(address)?;
+    let (master_token, _) = master_token_holder(&program, &account)?;
+
+    let delegate = delegate_address(&program.id(), &address, &wallet);
+    let permissions = permissions.iter().fold(0, |acc, p| acc | p.flag());
+
+    // Existing delegates have their permissions replaced instead.
+    let sig = if program.rpc().get_account(&delegate).is_ok() {
+        send_with_approval!(
+            program,
+            signer,
+            cfg.auto_approved,
+            xnft::accounts::UpdateDelegate {
+                authority: program.payer(),
+                config: config_address(&program.id()),
+                delegate,
+                master_metadata: account.master_metadata,
+                master_token,
+                xnft: address,
+            },
+            xnft::instruction::UpdateDelegate { permissions }
+        )?
+    } else {
+        send_with_approval!(
+            program,
+            signer,
+            cfg.auto_approved,
+            xnft::accounts::CreateDelegate {
+                authority: program.payer(),
+                config: config_address(&program.id()),
+                delegate,
+                master_metadata: account.master_metadata,
+                master_token,
+                system_program: system_program::ID,
+                wallet,
+                xnft: address,
+            },
+            xnft::instruction::CreateDelegate { permissions }
+        )?
+    };
+
+    println!("Signature: {sig}");
+    Ok(())
+}
+
 fn process_delete(cfg: Config, address: Pubkey) -> Result<()> {
     let (program, signer) = create_program_client(&cfg);
     let authority = program.payer();
@@ -167,6 +539,7 @@ fn process_delete(cfg: Config, address: Pubkey) -> Result<()> {
         cfg.auto_approved,
         xnft::accounts::DeleteXnft {
             authority,
+            curator: acc.curator.map(|c| c.pubkey),
             master_metadata: acc.master_metadata,
             master_mint: acc.master_mint,
             master_token,
@@ -192,14 +565,65 @@ fn process_get_account(
         AccountType::Access => {
             print_serializable!(program.account::<xnft::state::Access>(address)?, json)
         }
+        AccountType::AllowlistClaim => {
+            print_serializable!(
+                program.account::<xnft::state::AllowlistClaim>(address)?,
+                json
+            )
+        }
+        AccountType::Category => {
+            print_serializable!(program.account::<xnft::state::Category>(address)?, json)
+        }
+        AccountType::Curator => {
+            print_serializable!(program.account::<xnft::state::Curator>(address)?, json)
+        }
+        AccountType::Delegate => {
+            print_serializable!(program.account::<xnft::state::Delegate>(address)?, json)
+        }
+        AccountType::Escrow => {
+            print_serializable!(program.account::<xnft::state::Escrow>(address)?, json)
+        }
         AccountType::Install => {
             print_serializable!(program.account::<xnft::state::Install>(address)?, json)
         }
+        AccountType::ProtocolConfig => {
+            print_serializable!(
+                program.account::<xnft::state::ProtocolConfig>(address)?,
+                json
+            )
+        }
+        AccountType::Release => {
+            print_serializable!(program.account::<xnft::state::Release>(address)?, json)
+        }
         AccountType::Review => {
             print_serializable!(program.account::<xnft::state::Review>(address)?, json)
         }
+        AccountType::ReviewReply => {
+            print_serializable!(program.account::<xnft::state::ReviewReply>(address)?, json)
+        }
+        AccountType::UpdateProposal => {
+            print_serializable!(
+                program.account::<xnft::state::UpdateProposal>(address)?,
+                json
+            )
+        }
+        AccountType::VoucherNonce => {
+            print_serializable!(program.account::<xnft::state::VoucherNonce>(address)?, json)
+        }
         AccountType::Xnft => {
-            print_serializable!(program.account::<xnft::state::Xnft>(address)?, json)
+            let account = program.account::<xnft::state::Xnft>(address)?;
+            let categories = category_names(&program, account.categories);
+
+            // The account only stores category ids, which are resolved to the
+            // names in the registry for display.
+            if json {
+                let mut value = serde_json::to_value(&account)?;
+                value["categories"] = categories.into();
+                println!("{value}");
+            } else {
+                println!("{account:#?}");
+                println!("Categories: {}", categories.join(", "));
+            }
         }
     };
     Ok(())
@@ -210,12 +634,14 @@ fn process_grant_access(
     wallet: Pubkey,
     operation: AccessManagementOperation,
     xnft: Pubkey,
+    expires_ts: Option<i64>,
+    max_installs: Option<u32>,
 ) -> Result<()> {
     let (program, signer) = create_program_client(&cfg);
-    let (access, _) = Pubkey::find_program_address(
-        &["access".as_bytes(), wallet.as_ref(), xnft.as_ref()],
-        &program.id(),
-    );
+    let access = access_address(&program.id(), &wallet, &xnft);
+
+    let account: xnft::state::Xnft = program.account(xnft)?;
+    let (delegate, master_token) = access_delegate_of(&program, &xnft, &account)?;
 
     let sig = match operation {
         AccessManagementOperation::Grant => send_with_approval!(
@@ -225,11 +651,17 @@ fn process_grant_access(
             xnft::accounts::GrantAccess {
                 access,
                 authority: program.payer(),
+                config: config_address(&program.id()),
+                delegate,
+                master_token,
                 system_program: system_program::ID,
                 wallet,
                 xnft,
             },
-            xnft::instruction::GrantAccess {}
+            xnft::instruction::GrantAccess {
+                expires_ts,
+                max_installs,
+            }
         )?,
         AccessManagementOperation::Revoke => send_with_approval!(
             program,
@@ -238,6 +670,8 @@ fn process_grant_access(
             xnft::accounts::RevokeAccess {
                 access,
                 authority: program.payer(),
+                delegate,
+                master_token,
                 wallet,
                 xnft,
             },
@@ -249,7 +683,415 @@ fn process_grant_access(
     Ok(())
 }
 
-fn process_install(cfg: Config, address: Pubkey) -> Result<()> {
+fn process_grant_access_batch(
+    cfg: Config,
+    wallets: Vec<Pubkey>,
+    operation: AccessManagementOperation,
+    xnft: Pubkey,
+    expires_ts: Option<i64>,
+    max_installs: Option<u32>,
+) -> Result<()> {
+    let (program, signer) = create_program_client(&cfg);
+
+    let account: xnft::state::Xnft = program.account(xnft)?;
+    let (delegate, master_token) = access_delegate_of(&program, &xnft, &account)?;
+
+    // The access accounts of each batch are appended as remaining accounts in
+    // the same order as the wallets.
+    let accounts = |batch: &[Pubkey]| {
+        let mut accounts = match operation {
+            AccessManagementOperation::Grant => xnft::accounts::GrantAccessBatch {
+                authority: program.payer(),
+                config: config_address(&program.id()),
+                delegate,
+                master_token,
+                system_program: system_program::ID,
+                xnft,
+            }
+            .to_account_metas(None),
+            AccessManagementOperation::Revoke => xnft::accounts::RevokeAccessBatch {
+                authority: program.payer(),
+                delegate,
+                master_token,
+                xnft,
+            }
+            .to_account_metas(None),
+        };
+        accounts.extend(
+            batch
+                .iter()
+                .map(|w| AccountMeta::new(access_address(&program.id(), w, &xnft), false)),
+        );
+        accounts
+    };
+
+    let data = |batch: &[Pubkey]| match operation {
+        AccessManagementOperation::Grant => xnft::instruction::GrantAccessBatch {
+            wallets: batch.to_vec(),
+            expires_ts,
+            max_installs,
+        }
+        .data(),
+        AccessManagementOperation::Revoke => xnft::instruction::RevokeAccessBatch {}.data(),
+    };
+
+    // Pack as many wallets into each transaction as fit within the packet size limit.
+    let mut batches = Vec::new();
+    let mut rest = wallets.as_slice();
+    while !rest.is_empty() {
+        let fits = |n: usize| {
+            let ix = Instruction {
+                program_id: program.id(),
+                accounts: accounts(&rest[..n]),
+                data: data(&rest[..n]),
+            };
+            transaction_size(&ix, &program.payer()) <= PACKET_DATA_SIZE
+        };
+
+        let mut n = 1;
+        while n < rest.len() && fits(n + 1) {
+            n += 1;
+        }
+
+        let (batch, tail) = rest.split_at(n);
+        batches.push(batch);
+        rest = tail;
+    }
+
+    println!(
+        "Managing access of {} wallets in {} transactions",
+        wallets.len(),
+        batches.len()
+    );
+
+    for batch in batches {
+        let sig = match operation {
+            AccessManagementOperation::Grant => send_with_approval!(
+                program,
+                signer,
+                cfg.auto_approved,
+                accounts(batch),
+                xnft::instruction::GrantAccessBatch {
+                    wallets: batch.to_vec(),
+                    expires_ts,
+                    max_installs,
+                }
+            )?,
+            AccessManagementOperation::Revoke => send_with_approval!(
+                program,
+                signer,
+                cfg.auto_approved,
+                accounts(batch),
+                xnft::instruction::RevokeAccessBatch {}
+            )?,
+        };
+
+        println!("Signature: {sig}");
+    }
+
+    Ok(())
+}
+
+/// The accounts required to pay the install price of an xNFT, shared by
+/// new installations and subscription renewals.
+struct InstallPaymentAccounts {
+    authority_token: Option<Pubkey>,
+    config: Pubkey,
+    creators: Vec<AccountMeta>,
+    install_vault_token: Option<Pubkey>,
+    token_program: Option<Pubkey>,
+    treasury: Pubkey,
+    treasury_token: Option<Pubkey>,
+}
+
+fn access_address(program_id: &Pubkey, wallet: &Pubkey, xnft: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &["access".as_bytes(), wallet.as_ref(), xnft.as_ref()],
+        program_id,
+    )
+    .0
+}
+
+fn assigned_curator(program: &Program<Rc<Keypair>>, address: Pubkey) -> Result<Pubkey> {
+    let account: xnft::state::Xnft = program.account(address)?;
+    account
+        .curator
+        .map(|c| c.pubkey)
+        .ok_or_else(|| anyhow::anyhow!("xNFT {address} does not have an assigned curator"))
+}
+
+/// Returns the `Curator` account assigned to the xNFT for its verification, unless
+/// the signing wallet is itself the curator, as assigned before `Curator` accounts.
+fn verifying_curator(program: &Program<Rc<Keypair>>, address: Pubkey) -> Result<Option<Pubkey>> {
+    let curator = assigned_curator(program, address)?;
+    Ok((curator != program.payer()).then_some(curator))
+}
+
+fn category_address(program_id: &Pubkey, id: u8) -> Pubkey {
+    Pubkey::find_program_address(&["category".as_bytes(), &[id]], program_id).0
+}
+
+/// Resolves the ids of a category bitset to their registered names, falling
+/// back to the id itself for any category that is not in the registry.
+fn category_names(program: &Program<Rc<Keypair>>, categories: u64) -> Vec<String> {
+    xnft::state::Category::ids(categories)
+        .map(|id| {
+            program
+                .account::<xnft::state::Category>(category_address(&program.id(), id))
+                .map_or_else(|_| id.to_string(), |c| c.name)
+        })
+        .collect()
+}
+
+fn config_address(program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&["config".as_bytes()], program_id).0
+}
+
+fn delegate_address(program_id: &Pubkey, xnft: &Pubkey, wallet: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &["delegate".as_bytes(), xnft.as_ref(), wallet.as_ref()],
+        program_id,
+    )
+    .0
+}
+
+/// Returns the delegate account of the signing wallet for the xNFT, unless the
+/// wallet is the owner that the instruction is authorized by.
+fn delegate_of(program: &Program<Rc<Keypair>>, xnft: &Pubkey, owner: &Pubkey) -> Option<Pubkey> {
+    (*owner != program.payer()).then(|| delegate_address(&program.id(), xnft, &program.payer()))
+}
+
+/// Returns the delegate account of the signing wallet for a private xNFT and the
+/// master token account of the owner that granted it, unless the wallet is the
+/// install authority that the instruction is authorized by.
+fn access_delegate_of(
+    program: &Program<Rc<Keypair>>,
+    xnft: &Pubkey,
+    account: &xnft::state::Xnft,
+) -> Result<(Option<Pubkey>, Option<Pubkey>)> {
+    let delegate = delegate_of(
+        program,
+        xnft,
+        &account.install_authority.unwrap_or_default(),
+    );
+    let master_token = match delegate {
+        Some(_) => Some(master_token_holder(program, account)?.0),
+        None => None,
+    };
+    Ok((delegate, master_token))
+}
+
+fn escrow_address(program_id: &Pubkey, install: &Pubkey, edition: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            "escrow".as_bytes(),
+            install.as_ref(),
+            &edition.to_le_bytes(),
+        ],
+        program_id,
+    )
+}
+
+fn escrow_token_address(program_id: &Pubkey, install: &Pubkey, edition: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            "escrow_token".as_bytes(),
+            install.as_ref(),
+            &edition.to_le_bytes(),
+        ],
+        program_id,
+    )
+    .0
+}
+
+/// Finds the token account holding the master token of the xNFT and its owner,
+/// which is not the signing wallet when it is acting as a delegate.
+fn master_token_holder(
+    program: &Program<Rc<Keypair>>,
+    account: &xnft::state::Xnft,
+) -> Result<(Pubkey, Pubkey)> {
+    let holder = program
+        .rpc()
+        .get_token_largest_accounts(&account.master_mint)?
+        .into_iter()
+        .find(|t| t.amount.amount == "1")
+        .ok_or_else(|| anyhow::anyhow!("No holder found for the master token of the xNFT"))?;
+
+    let address = Pubkey::from_str(&holder.address)?;
+    let token: TokenAccount = program.account(address)?;
+    Ok((address, token.owner))
+}
+
+fn metadata_address(mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            "metadata".as_bytes(),
+            Metadata::id().as_ref(),
+            mint.as_ref(),
+        ],
+        &Metadata::id(),
+    )
+    .0
+}
+
+/// Refundable install payments are held in escrow accounts for the installation.
+fn install_escrow_accounts(
+    program_id: &Pubkey,
+    account: &xnft::state::Xnft,
+    install: &Pubkey,
+) -> (Option<Pubkey>, Option<Pubkey>) {
+    match account.refund_window {
+        Some(_) if account.install_price > 0 => {
+            let edition = account.total_installs;
+            let (escrow, _) = escrow_address(program_id, install, edition);
+            let escrow_token = account
+                .install_mint
+                .map(|_| escrow_token_address(program_id, install, edition));
+            (Some(escrow), escrow_token)
+        }
+        _ => (None, None),
+    }
+}
+
+/// Installs that mint receipts create the Token-2022 mint for the next edition
+/// and the associated token account of the installing wallet.
+fn install_receipt_accounts(
+    program_id: &Pubkey,
+    account: &xnft::state::Xnft,
+    address: &Pubkey,
+    installer: &Pubkey,
+) -> (Option<Pubkey>, Option<Pubkey>) {
+    if !account.mint_install_receipts {
+        return (None, None);
+    }
+
+    let (mint, _) = Pubkey::find_program_address(
+        &[
+            "receipt".as_bytes(),
+            address.as_ref(),
+            &account.total_installs.to_le_bytes(),
+        ],
+        program_id,
+    );
+    let token =
+        get_associated_token_address_with_program_id(installer, &mint, &anchor_spl::token_2022::ID);
+    (Some(mint), Some(token))
+}
+
+fn install_payment_accounts(
+    program: &Program<Rc<Keypair>>,
+    account: &xnft::state::Xnft,
+    authority: &Pubkey,
+) -> Result<InstallPaymentAccounts> {
+    let config = config_address(&program.id());
+    let protocol: xnft::state::ProtocolConfig = program.account(config)?;
+
+    // SPL token denominated installs require the token accounts of the installing
+    // wallet, the install vault and the protocol treasury for the mint's owning token program.
+    let (authority_token, install_vault_token, treasury_token, token_program) =
+        match account.install_mint {
+            Some(mint) => {
+                let token_program = program.rpc().get_account(&mint)?.owner;
+                (
+                    Some(get_associated_token_address_with_program_id(
+                        authority,
+                        &mint,
+                        &token_program,
+                    )),
+                    Some(get_associated_token_address_with_program_id(
+                        &account.install_vault,
+                        &mint,
+                        &token_program,
+                    )),
+                    Some(get_associated_token_address_with_program_id(
+                        &protocol.treasury,
+                        &mint,
+                        &token_program,
+                    )),
+                    Some(token_program),
+                )
+            }
+            None => (None, None, None, None),
+        };
+
+    // Installs that split payments across the metadata creators expect each
+    // creator's wallet, or their token account for the install mint, in order.
+    let mut creators = Vec::new();
+    if account.split_install_payments {
+        let metadata: MetadataAccount = program.account(account.master_metadata)?;
+        for c in metadata.data.creators.iter().flatten() {
+            let address = match (account.install_mint, token_program) {
+                (Some(mint), Some(tp)) => {
+                    get_associated_token_address_with_program_id(&c.address, &mint, &tp)
+                }
+                _ => c.address,
+            };
+            creators.push(AccountMeta::new(address, false));
+        }
+    }
+
+    Ok(InstallPaymentAccounts {
+        authority_token,
+        config,
+        creators,
+        install_vault_token,
+        token_program,
+        treasury: protocol.treasury,
+        treasury_token,
+    })
+}
+
+/// The serialized size of a transaction of the instruction signed by the payer.
+fn transaction_size(ix: &Instruction, payer: &Pubkey) -> usize {
+    let message = Message::new(&[ix.clone()], Some(payer));
+    1 + 64 * message.header.num_required_signatures as usize + message.serialize().len()
+}
+
+/// Reads a list of wallet public keys from a file, one per line.
+fn read_wallets(path: &Path) -> Result<Vec<Pubkey>> {
+    std::fs::read_to_string(path)?
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .map(|l| Pubkey::from_str(l).map_err(|e| anyhow::anyhow!("Invalid wallet {l}: {e}")))
+        .collect()
+}
+
+/// Reads the content to be hashed from an http(s) URL or a local file path.
+fn read_content(source: &str) -> Result<Vec<u8>> {
+    if source.starts_with("http://") || source.starts_with("https://") {
+        let res = reqwest::blocking::get(source)?.error_for_status()?;
+        Ok(res.bytes()?.to_vec())
+    } else {
+        Ok(std::fs::read(source)?)
+    }
+}
+
+fn process_init_config(cfg: Config, treasury: Pubkey, fee_basis_points: u16) -> Result<()> {
+    let (program, signer) = create_program_client(&cfg);
+    let (program_data, _) =
+        Pubkey::find_program_address(&[program.id().as_ref()], &bpf_loader_upgradeable::ID);
+
+    let sig = send_with_approval!(
+        program,
+        signer,
+        cfg.auto_approved,
+        xnft::accounts::InitializeProtocolConfig {
+            authority: program.payer(),
+            config: config_address(&program.id()),
+            program: program.id(),
+            program_data,
+            system_program: system_program::ID,
+            treasury,
+        },
+        xnft::instruction::InitializeProtocolConfig { fee_basis_points }
+    )?;
+
+    println!("Signature: {sig}");
+    Ok(())
+}
+
+fn process_install(cfg: Config, address: Pubkey, gate_mint: Option<Pubkey>) -> Result<()> {
     let (program, signer) = create_program_client(&cfg);
     let authority = program.payer();
 
@@ -259,19 +1101,399 @@ fn process_install(cfg: Config, address: Pubkey) -> Result<()> {
         &program.id(),
     );
 
+    let payment = install_payment_accounts(&program, &account, &authority)?;
+
+    let (escrow, escrow_token) = install_escrow_accounts(&program.id(), &account, &install);
+    let (receipt_mint, receipt_token) =
+        install_receipt_accounts(&program.id(), &account, &address, &authority);
+
+    // Gated installs check the installing wallet's token account of the gating
+    // mint, or of a held NFT and its metadata for collection gates.
+    let gate_mint = match &account.install_gate {
+        Some(InstallGate::Token { mint, .. }) => Some(*mint),
+        Some(InstallGate::Collection { .. }) => Some(gate_mint.ok_or_else(|| {
+            anyhow::anyhow!("xNFT {address} is gated by a collection, provide a held NFT mint")
+        })?),
+        None => None,
+    };
+    let gate_token = match gate_mint {
+        Some(mint) => {
+            let token_program = program.rpc().get_account(&mint)?.owner;
+            Some(get_associated_token_address_with_program_id(
+                &authority,
+                &mint,
+                &token_program,
+            ))
+        }
+        None => None,
+    };
+    let gate_metadata = match account.install_gate {
+        Some(InstallGate::Collection { .. }) => gate_mint.map(|m| metadata_address(&m)),
+        _ => None,
+    };
+
+    let mut accounts = xnft::accounts::CreateInstall {
+        base: xnft::accounts::InstallAccounts {
+            associated_token_program: receipt_mint.map(|_| spl_associated_token_account::ID),
+            authority,
+            authority_token: payment.authority_token,
+            config: payment.config,
+            escrow,
+            escrow_token,
+            install,
+            install_mint: account.install_mint,
+            install_vault: account.install_vault,
+            install_vault_token: payment.install_vault_token,
+            master_metadata: Some(account.master_metadata),
+            receipt_mint,
+            receipt_token,
+            receipt_token_program: receipt_mint.map(|_| anchor_spl::token_2022::ID),
+            system_program: system_program::ID,
+            target: authority,
+            token_program: payment.token_program,
+            treasury: payment.treasury,
+            treasury_token: payment.treasury_token,
+            xnft: address,
+        },
+        gate_metadata,
+        gate_token,
+    }
+    .to_account_metas(None);
+    accounts.extend(payment.creators);
+
+    let sig = send_with_approval!(
+        program,
+        signer,
+        cfg.auto_approved,
+        accounts,
+        xnft::instruction::CreateInstall {}
+    )?;
+
+    println!("Signature: {sig}");
+    Ok(())
+}
+
+fn process_issue_voucher(
+    cfg: Config,
+    wallet: Pubkey,
+    xnft: Pubkey,
+    expires_ts: i64,
+    nonce: u64,
+) -> Result<()> {
+    let voucher = InstallVoucher {
+        expires_ts,
+        nonce,
+        wallet,
+        xnft,
+    };
+
+    println!(
+        "Signature: {}",
+        cfg.keypair.sign_message(&voucher.message())
+    );
+    Ok(())
+}
+
+fn process_migrate(cfg: Config, xnft: Pubkey) -> Result<()> {
+    let (program, signer) = create_program_client(&cfg);
+
     let sig = send_with_approval!(
         program,
         signer,
         cfg.auto_approved,
-        xnft::accounts::CreateInstall {
+        xnft::accounts::MigrateXnft {
+            payer: program.payer(),
+            system_program: system_program::ID,
+            xnft,
+        },
+        xnft::instruction::MigrateXnft {}
+    )?;
+
+    println!("Signature: {sig}");
+    Ok(())
+}
+
+fn process_moderate(
+    cfg: Config,
+    address: Pubkey,
+    admin: bool,
+    reason: u8,
+    unsuspend: bool,
+) -> Result<()> {
+    let (program, signer) = create_program_client(&cfg);
+
+    let (curator, config) = if admin {
+        let config = config_address(&program.id());
+        (None, Some(config))
+    } else {
+        (Some(assigned_curator(&program, address)?), None)
+    };
+
+    let sig = send_with_approval!(
+        program,
+        signer,
+        cfg.auto_approved,
+        xnft::accounts::ModerateXnft {
+            authority: program.payer(),
+            config,
+            curator,
+            xnft: address,
+        },
+        xnft::instruction::ModerateXnft {
+            flag: !unsuspend,
+            reason,
+        }
+    )?;
+
+    println!("Signature: {sig}");
+    Ok(())
+}
+
+fn process_redeem_voucher(
+    cfg: Config,
+    address: Pubkey,
+    expires_ts: i64,
+    nonce: u64,
+    signature: Signature,
+) -> Result<()> {
+    let (program, signer) = create_program_client(&cfg);
+    let authority = program.payer();
+
+    let account: xnft::state::Xnft = program.account(address)?;
+    let install_authority = account
+        .install_authority
+        .ok_or_else(|| anyhow::anyhow!("xNFT {address} does not have an install authority"))?;
+
+    let voucher = InstallVoucher {
+        expires_ts,
+        nonce,
+        wallet: authority,
+        xnft: address,
+    };
+
+    // The install authority's signature is verified by the Ed25519 program in
+    // an instruction placed directly before the one redeeming the voucher.
+    let ed25519_ix = vouchers::new_ed25519_instruction(
+        &install_authority,
+        signature.as_ref().try_into()?,
+        &voucher.message(),
+    );
+
+    let (install, _) = Pubkey::find_program_address(
+        &["install".as_bytes(), authority.as_ref(), address.as_ref()],
+        &program.id(),
+    );
+    let (nonce_account, _) = Pubkey::find_program_address(
+        &[
+            "voucher_nonce".as_bytes(),
+            address.as_ref(),
+            &nonce.to_le_bytes(),
+        ],
+        &program.id(),
+    );
+
+    let payment = install_payment_accounts(&program, &account, &authority)?;
+    let (escrow, escrow_token) = install_escrow_accounts(&program.id(), &account, &install);
+    let (receipt_mint, receipt_token) =
+        install_receipt_accounts(&program.id(), &account, &address, &authority);
+
+    let mut accounts = xnft::accounts::CreateVoucherInstall {
+        base: xnft::accounts::InstallAccounts {
+            associated_token_program: receipt_mint.map(|_| spl_associated_token_account::ID),
             authority,
+            authority_token: payment.authority_token,
+            config: payment.config,
+            escrow,
+            escrow_token,
             install,
+            install_mint: account.install_mint,
             install_vault: account.install_vault,
+            install_vault_token: payment.install_vault_token,
+            master_metadata: Some(account.master_metadata),
+            receipt_mint,
+            receipt_token,
+            receipt_token_program: receipt_mint.map(|_| anchor_spl::token_2022::ID),
             system_program: system_program::ID,
             target: authority,
+            token_program: payment.token_program,
+            treasury: payment.treasury,
+            treasury_token: payment.treasury_token,
             xnft: address,
         },
-        xnft::instruction::CreateInstall {}
+        instructions: sysvar::instructions::ID,
+        nonce: nonce_account,
+        system_program: system_program::ID,
+    }
+    .to_account_metas(None);
+    accounts.extend(payment.creators);
+
+    let sig = send_with_approval!(
+        program,
+        signer,
+        cfg.auto_approved,
+        [ed25519_ix],
+        accounts,
+        xnft::instruction::CreateVoucherInstall { voucher }
+    )?;
+
+    println!("Signature: {sig}");
+    Ok(())
+}
+
+fn process_refund(cfg: Config, address: Pubkey) -> Result<()> {
+    let (program, signer) = create_program_client(&cfg);
+    let authority = program.payer();
+
+    let (install, _) = Pubkey::find_program_address(
+        &["install".as_bytes(), authority.as_ref(), address.as_ref()],
+        &program.id(),
+    );
+    let install_account: xnft::state::Install = program.account(install)?;
+    let (escrow, _) = escrow_address(&program.id(), &install, install_account.edition);
+    let account: xnft::state::Escrow = program.account(escrow)?;
+
+    let (escrow_token, payer_token, token_program) = match account.mint {
+        Some(mint) => {
+            let token_program = program.rpc().get_account(&mint)?.owner;
+            (
+                Some(escrow_token_address(
+                    &program.id(),
+                    &install,
+                    account.edition,
+                )),
+                Some(get_associated_token_address_with_program_id(
+                    &account.payer,
+                    &mint,
+                    &token_program,
+                )),
+                Some(token_program),
+            )
+        }
+        None => (None, None, None),
+    };
+
+    let sig = send_with_approval!(
+        program,
+        signer,
+        cfg.auto_approved,
+        xnft::accounts::RefundInstall {
+            authority,
+            escrow,
+            escrow_token,
+            install,
+            install_mint: account.mint,
+            payer: account.payer,
+            payer_token,
+            token_program,
+        },
+        xnft::instruction::RefundInstall {}
+    )?;
+
+    println!("Signature: {sig}");
+    Ok(())
+}
+
+fn process_renew(cfg: Config, address: Pubkey) -> Result<()> {
+    let (program, signer) = create_program_client(&cfg);
+    let authority = program.payer();
+
+    let account: xnft::state::Xnft = program.account(address)?;
+    let (install, _) = Pubkey::find_program_address(
+        &["install".as_bytes(), authority.as_ref(), address.as_ref()],
+        &program.id(),
+    );
+
+    let payment = install_payment_accounts(&program, &account, &authority)?;
+
+    let mut accounts = xnft::accounts::RenewInstall {
+        authority,
+        authority_token: payment.authority_token,
+        config: payment.config,
+        install,
+        install_mint: account.install_mint,
+        install_vault: account.install_vault,
+        install_vault_token: payment.install_vault_token,
+        master_metadata: Some(account.master_metadata),
+        system_program: system_program::ID,
+        token_program: payment.token_program,
+        treasury: payment.treasury,
+        treasury_token: payment.treasury_token,
+        xnft: address,
+    }
+    .to_account_metas(None);
+    accounts.extend(payment.creators);
+
+    let sig = send_with_approval!(
+        program,
+        signer,
+        cfg.auto_approved,
+        accounts,
+        xnft::instruction::RenewInstall {}
+    )?;
+
+    println!("Signature: {sig}");
+    Ok(())
+}
+
+fn process_revoke_install(cfg: Config, wallet: Pubkey, address: Pubkey) -> Result<()> {
+    let (program, signer) = create_program_client(&cfg);
+
+    let account: xnft::state::Xnft = program.account(address)?;
+    let (master_token, owner) = master_token_holder(&program, &account)?;
+    let (install, _) = Pubkey::find_program_address(
+        &["install".as_bytes(), wallet.as_ref(), address.as_ref()],
+        &program.id(),
+    );
+    let install_account: xnft::state::Install = program.account(install)?;
+
+    let sig = send_with_approval!(
+        program,
+        signer,
+        cfg.auto_approved,
+        xnft::accounts::RevokeInstall {
+            authority: program.payer(),
+            escrow: escrow_address(&program.id(), &install, install_account.edition).0,
+            install,
+            installer: wallet,
+            master_token: (owner == program.payer()).then_some(master_token),
+            xnft: address,
+        },
+        xnft::instruction::RevokeInstall {}
+    )?;
+
+    println!("Signature: {sig}");
+    Ok(())
+}
+
+fn process_set_allowlist(cfg: Config, address: Pubkey, wallets: Option<PathBuf>) -> Result<()> {
+    let (program, signer) = create_program_client(&cfg);
+    let account: xnft::state::Xnft = program.account(address)?;
+
+    let root = match wallets {
+        Some(path) => {
+            let root = MerkleTree::new(&read_wallets(&path)?)
+                .root()
+                .ok_or_else(|| anyhow::anyhow!("The allowlist file has no wallets"))?;
+            println!("Root: {}", to_hex(root));
+            Some(root)
+        }
+        None => None,
+    };
+
+    let (delegate, master_token) = access_delegate_of(&program, &address, &account)?;
+
+    let sig = send_with_approval!(
+        program,
+        signer,
+        cfg.auto_approved,
+        xnft::accounts::SetAllowlist {
+            authority: program.payer(),
+            config: config_address(&program.id()),
+            delegate,
+            master_token,
+            xnft: address,
+        },
+        xnft::instruction::SetAllowlist { root }
     )?;
 
     println!("Signature: {sig}");
@@ -281,7 +1503,7 @@ fn process_install(cfg: Config, address: Pubkey) -> Result<()> {
 fn process_set_curator(cfg: Config, address: Pubkey, curator: Pubkey) -> Result<()> {
     let (program, signer) = create_program_client(&cfg);
     let account: xnft::state::Xnft = program.account(address)?;
-    let master_token = get_associated_token_address(&program.payer(), &account.master_mint);
+    let (master_token, owner) = master_token_holder(&program, &account)?;
 
     let sig = send_with_approval!(
         program,
@@ -289,8 +1511,11 @@ fn process_set_curator(cfg: Config, address: Pubkey, curator: Pubkey) -> Result<
         cfg.auto_approved,
         xnft::accounts::SetCurator {
             authority: program.payer(),
+            config: config_address(&program.id()),
             curator,
+            delegate: delegate_of(&program, &address, &owner),
             master_token,
+            previous_curator: account.curator.map(|c| c.pubkey),
             xnft: address,
         },
         xnft::instruction::SetCurator {}
@@ -300,10 +1525,94 @@ fn process_set_curator(cfg: Config, address: Pubkey, curator: Pubkey) -> Result<
     Ok(())
 }
 
-fn process_toggle_suspend(cfg: Config, address: Pubkey) -> Result<()> {
+fn process_set_install_gate(
+    cfg: Config,
+    address: Pubkey,
+    collection: Option<Pubkey>,
+    token: Option<(Pubkey, u64)>,
+) -> Result<()> {
     let (program, signer) = create_program_client(&cfg);
     let account: xnft::state::Xnft = program.account(address)?;
-    let master_token = get_associated_token_address(&program.payer(), &account.master_mint);
+    let (master_token, owner) = master_token_holder(&program, &account)?;
+
+    let sig = send_with_approval!(
+        program,
+        signer,
+        cfg.auto_approved,
+        xnft::accounts::SetInstallGate {
+            authority: program.payer(),
+            config: config_address(&program.id()),
+            delegate: delegate_of(&program, &address, &owner),
+            master_token,
+            xnft: address,
+        },
+        xnft::instruction::SetInstallGate {
+            gate: match (collection, token) {
+                (Some(mint), _) => Some(InstallGate::Collection { mint }),
+                (_, Some((mint, amount))) => Some(InstallGate::Token { mint, amount }),
+                _ => None,
+            },
+        }
+    )?;
+
+    println!("Signature: {sig}");
+    Ok(())
+}
+
+fn process_settle(cfg: Config, escrow: Pubkey) -> Result<()> {
+    let (program, signer) = create_program_client(&cfg);
+    let authority = program.payer();
+
+    let account: xnft::state::Escrow = program.account(escrow)?;
+    let xnft_account: xnft::state::Xnft = program.account(account.xnft)?;
+    let master_token = get_associated_token_address(&authority, &xnft_account.master_mint);
+
+    // The escrow pays out in its own mint and split, regardless of the current
+    // install mint and split of the xNFT.
+    let xnft_account = xnft::state::Xnft {
+        install_mint: account.mint,
+        split_install_payments: account.split_install_payments,
+        ..xnft_account
+    };
+    let payment = install_payment_accounts(&program, &xnft_account, &escrow)?;
+
+    let mut accounts = xnft::accounts::SettleInstall {
+        authority,
+        config: payment.config,
+        escrow,
+        escrow_token: account
+            .mint
+            .map(|_| escrow_token_address(&program.id(), &account.install, account.edition)),
+        install_mint: account.mint,
+        install_vault: xnft_account.install_vault,
+        install_vault_token: payment.install_vault_token,
+        master_metadata: Some(xnft_account.master_metadata),
+        master_token,
+        payer: account.payer,
+        token_program: payment.token_program,
+        treasury: payment.treasury,
+        treasury_token: payment.treasury_token,
+        xnft: account.xnft,
+    }
+    .to_account_metas(None);
+    accounts.extend(payment.creators);
+
+    let sig = send_with_approval!(
+        program,
+        signer,
+        cfg.auto_approved,
+        accounts,
+        xnft::instruction::SettleInstall {}
+    )?;
+
+    println!("Signature: {sig}");
+    Ok(())
+}
+
+fn process_toggle_suspend(cfg: Config, address: Pubkey, reason: u8) -> Result<()> {
+    let (program, signer) = create_program_client(&cfg);
+    let account: xnft::state::Xnft = program.account(address)?;
+    let (master_token, owner) = master_token_holder(&program, &account)?;
 
     let sig = send_with_approval!(
         program,
@@ -311,11 +1620,13 @@ fn process_toggle_suspend(cfg: Config, address: Pubkey) -> Result<()> {
         cfg.auto_approved,
         xnft::accounts::SetSuspended {
             authority: program.payer(),
+            delegate: delegate_of(&program, &address, &owner),
             master_token,
             xnft: address,
         },
         xnft::instruction::SetSuspended {
             flag: !account.suspended,
+            reason,
         }
     )?;
 
@@ -337,6 +1648,7 @@ fn process_transfer(cfg: Config, xnft: Pubkey, recipient: Pubkey) -> Result<()>
         xnft::accounts::Transfer {
             associated_token_program: spl_associated_token_account::ID,
             authority: program.payer(),
+            config: config_address(&program.id()),
             destination,
             master_mint: account.master_mint,
             recipient,
@@ -352,6 +1664,30 @@ fn process_transfer(cfg: Config, xnft: Pubkey, recipient: Pubkey) -> Result<()>
     Ok(())
 }
 
+fn process_undelegate(cfg: Config, wallet: Pubkey, address: Pubkey) -> Result<()> {
+    let (program, signer) = create_program_client(&cfg);
+    let account: xnft::state::Xnft = program.account(address)?;
+    let (master_token, _) = master_token_holder(&program, &account)?;
+
+    let sig = send_with_approval!(
+        program,
+        signer,
+        cfg.auto_approved,
+        xnft::accounts::DeleteDelegate {
+            authority: program.payer(),
+            delegate: delegate_address(&program.id(), &address, &wallet),
+            master_metadata: account.master_metadata,
+            master_token,
+            receiver: program.payer(),
+            xnft: address,
+        },
+        xnft::instruction::DeleteDelegate {}
+    )?;
+
+    println!("Signature: {sig}");
+    Ok(())
+}
+
 fn process_uninstall(cfg: Config, address: Pubkey) -> Result<()> {
     let (program, signer) = create_program_client(&cfg);
     let authority = program.payer();
@@ -379,12 +1715,15 @@ fn process_uninstall(cfg: Config, address: Pubkey) -> Result<()> {
 
 fn process_unverify(cfg: Config, address: Pubkey) -> Result<()> {
     let (program, signer) = create_program_client(&cfg);
+    let curator = verifying_curator(&program, address)?;
     let sig = send_with_approval!(
         program,
         signer,
         cfg.auto_approved,
         xnft::accounts::SetCuratorVerification {
-            curator: program.payer(),
+            authority: program.payer(),
+            config: config_address(&program.id()),
+            curator,
             xnft: address,
         },
         xnft::instruction::SetCuratorVerification { value: false }
@@ -396,12 +1735,15 @@ fn process_unverify(cfg: Config, address: Pubkey) -> Result<()> {
 
 fn process_verify(cfg: Config, address: Pubkey) -> Result<()> {
     let (program, signer) = create_program_client(&cfg);
+    let curator = verifying_curator(&program, address)?;
     let sig = send_with_approval!(
         program,
         signer,
         cfg.auto_approved,
         xnft::accounts::SetCuratorVerification {
-            curator: program.payer(),
+            authority: program.payer(),
+            config: config_address(&program.id()),
+            curator,
             xnft: address,
         },
         xnft::instruction::SetCuratorVerification { value: true }
@@ -410,3 +1752,27 @@ fn process_verify(cfg: Config, address: Pubkey) -> Result<()> {
     println!("Signature: {sig}");
     Ok(())
 }
+
+fn process_verify_content(cfg: Config, address: Pubkey, source: Option<String>) -> Result<()> {
+    let (program, _) = create_program_client(&cfg);
+    let account: xnft::state::Xnft = program.account(address)?;
+
+    let expected = account
+        .content_hash
+        .ok_or_else(|| anyhow::anyhow!("xNFT {address} does not have a content hash"))?;
+
+    let source = source.unwrap_or(account.uri);
+    let content = read_content(&source)?;
+    let digest = hash(&content).to_bytes();
+
+    println!("Source:   {source}");
+    println!("Expected: {}", to_hex(expected));
+    println!("Actual:   {}", to_hex(digest));
+
+    if digest != expected {
+        return Err(anyhow::anyhow!("Content hash mismatch"));
+    }
+
+    println!("Content hash verified");
+    Ok(())
+}
@@ -49,15 +49,22 @@ macro_rules! print_serializable {
 pub(crate) use print_serializable;
 
 macro_rules! send_with_approval {
-    ($program:ident, $signer:expr, $auto_approved:expr, $accs:expr, $args:expr) => {{
+    ($program:ident, $signer:expr, $auto_approved:expr, $accs:expr, $args:expr) => {
+        $crate::util::send_with_approval!($program, $signer, $auto_approved, [], $accs, $args)
+    };
+    // Instructions listed before the accounts are sent ahead of the program instruction.
+    ($program:ident, $signer:expr, $auto_approved:expr, [$($ix:expr),*], $accs:expr, $args:expr) => {{
+        let __args = $args;
+        let __name = $crate::util::type_name_of_value(&__args);
         let __req = $program
             .request()
+            $(.instruction($ix))*
             .accounts($accs)
-            .args($args)
+            .args(__args)
             .signer($signer.as_ref());
 
         println!("Transactions:");
-        println!("[0] {}", $crate::util::type_name_of_value(&$args));
+        println!("[0] {}", __name);
         println!();
 
         if !$auto_approved {
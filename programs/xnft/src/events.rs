@@ -15,20 +15,75 @@
 
 use anchor_lang::prelude::*;
 
-use crate::state::Tag;
+use crate::state::{InstallGate, SuspensionRole};
 
 #[event]
 pub struct AccessGranted {
+    pub expires_ts: Option<i64>,
+    pub max_installs: Option<u32>,
     pub wallet: Pubkey,
     pub xnft: Pubkey,
 }
 
+#[event]
+pub struct AllowlistUpdated {
+    pub root: Option<[u8; 32]>,
+    pub xnft: Pubkey,
+}
+
+#[event]
+pub struct InstallGateUpdated {
+    pub gate: Option<InstallGate>,
+    pub xnft: Pubkey,
+}
+
 #[event]
 pub struct InstallationCreated {
     pub installer: Pubkey,
     pub xnft: Pubkey,
 }
 
+#[event]
+pub struct InstallationRefunded {
+    pub amount: u64,
+    pub installer: Pubkey,
+    pub xnft: Pubkey,
+}
+
+#[event]
+pub struct InstallationRenewed {
+    pub expires_ts: i64,
+    pub installer: Pubkey,
+    pub xnft: Pubkey,
+}
+
+#[event]
+pub struct InstallationRevoked {
+    pub authority: Pubkey,
+    pub installer: Pubkey,
+    pub xnft: Pubkey,
+}
+
+#[event]
+pub struct InstallationSettled {
+    pub amount: u64,
+    pub install: Pubkey,
+    pub xnft: Pubkey,
+}
+
+#[event]
+pub struct ProtocolPauseUpdated {
+    pub admin: Pubkey,
+    pub paused: bool,
+}
+
+#[event]
+pub struct ReleasePublished {
+    pub publisher: Pubkey,
+    pub version: u32,
+    pub xnft: Pubkey,
+}
+
 #[event]
 pub struct ReviewCreated {
     pub author: Pubkey,
@@ -36,13 +91,98 @@ pub struct ReviewCreated {
     pub xnft: Pubkey,
 }
 
+#[event]
+pub struct ReviewHidden {
+    pub curator: Pubkey,
+    pub hidden: bool,
+    pub reason: u8,
+    pub review: Pubkey,
+    pub xnft: Pubkey,
+}
+
+#[event]
+pub struct ReviewRemoved {
+    pub author: Pubkey,
+    pub curator: Pubkey,
+    pub reason: u8,
+    pub review: Pubkey,
+    pub xnft: Pubkey,
+}
+
+#[event]
+pub struct ReviewReplyCreated {
+    pub author: Pubkey,
+    pub review: Pubkey,
+    pub xnft: Pubkey,
+}
+
+#[event]
+pub struct ReviewReplyDeleted {
+    pub review: Pubkey,
+    pub xnft: Pubkey,
+}
+
+#[event]
+pub struct ReviewReplyUpdated {
+    pub author: Pubkey,
+    pub review: Pubkey,
+    pub xnft: Pubkey,
+}
+
+#[event]
+pub struct ReviewUpdated {
+    pub author: Pubkey,
+    pub previous_rating: u8,
+    pub rating: u8,
+    pub xnft: Pubkey,
+}
+
+#[event]
+pub struct UpdateProposalCreated {
+    pub curator: Pubkey,
+    pub proposer: Pubkey,
+    pub xnft: Pubkey,
+}
+
+#[event]
+pub struct UpdateProposalReviewed {
+    pub approved: bool,
+    pub curator: Pubkey,
+    pub xnft: Pubkey,
+}
+
 #[event]
 pub struct XnftCreated {
-    pub tag: Tag,
+    pub categories: u64,
+    pub content_hash: Option<[u8; 32]>,
+    pub xnft: Pubkey,
+}
+
+#[event]
+pub struct XnftRolledBack {
+    pub content_hash: Option<[u8; 32]>,
+    pub previous_release: u32,
+    pub release: u32,
+    pub xnft: Pubkey,
+}
+
+#[event]
+pub struct XnftSuspended {
+    pub authority: Pubkey,
+    pub reason: u8,
+    pub role: SuspensionRole,
+    pub xnft: Pubkey,
+}
+
+#[event]
+pub struct XnftUnsuspended {
+    pub authority: Pubkey,
+    pub role: SuspensionRole,
     pub xnft: Pubkey,
 }
 
 #[event]
 pub struct XnftUpdated {
+    pub content_hash: Option<[u8; 32]>,
     pub xnft: Pubkey,
 }
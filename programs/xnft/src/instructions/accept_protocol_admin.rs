@@ -0,0 +1,39 @@
+// Copyright (C) 2023 Blue Coral, Inc.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use anchor_lang::prelude::*;
+
+use crate::state::ProtocolConfig;
+use crate::CustomError;
+
+#[derive(Accounts)]
+pub struct AcceptProtocolAdmin<'info> {
+    #[account(
+        mut,
+        seeds = ["config".as_bytes()],
+        bump = config.bump,
+        constraint = config.pending_admin == Some(*new_admin.key) @ CustomError::PendingAdminMismatch,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    pub new_admin: Signer<'info>,
+}
+
+pub fn accept_protocol_admin_handler(ctx: Context<AcceptProtocolAdmin>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    config.admin = *ctx.accounts.new_admin.key;
+    config.pending_admin = None;
+    Ok(())
+}
@@ -0,0 +1,129 @@
+// Copyright (C) 2023 Blue Coral, Inc.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use anchor_lang::prelude::*;
+use anchor_spl::metadata::{Metadata, MetadataAccount};
+use anchor_spl::token::TokenAccount;
+
+use super::{apply_updates, publish_release, verify_updater};
+use crate::state::{Delegate, ProposalStatus, ProtocolConfig, Release, UpdateProposal, Xnft};
+use crate::CustomError;
+
+#[derive(Accounts)]
+pub struct ApplyUpdateProposal<'info> {
+    #[account(
+        mut,
+        close = proposer,
+        has_one = proposer,
+        has_one = xnft,
+        constraint = proposal.status == ProposalStatus::Approved @ CustomError::ProposalNotApproved,
+    )]
+    pub proposal: Account<'info, UpdateProposal>,
+
+    #[account(
+        mut,
+        has_one = master_metadata,
+        constraint = xnft.curator.as_ref().map(|c| c.pubkey) == Some(proposal.curator) @ CustomError::CuratorMismatch,
+        constraint = xnft.curator.as_ref().is_some_and(|c| c.verified) @ CustomError::CuratorNotVerified,
+    )]
+    pub xnft: Account<'info, Xnft>,
+
+    #[account(
+        constraint = master_token.mint == xnft.master_mint,
+        constraint = master_token.amount == 1,
+    )]
+    pub master_token: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = master_metadata.is_mutable @ CustomError::MetadataIsImmutable,
+    )]
+    pub master_metadata: Account<'info, MetadataAccount>,
+
+    #[account(
+        seeds = [
+            "delegate".as_bytes(),
+            xnft.key().as_ref(),
+            updater.key().as_ref(),
+        ],
+        bump = delegate.bump,
+    )]
+    pub delegate: Option<Account<'info, Delegate>>,
+
+    /// CHECK: is validated by the `has_one` constraint on `proposal` to refund the rent.
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = updater,
+        space = Release::LEN,
+        seeds = [
+            "release".as_bytes(),
+            xnft.key().as_ref(),
+            (xnft.total_releases + 1).to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub release: Option<Account<'info, Release>>,
+
+    #[account(mut)]
+    pub updater: Signer<'info>,
+
+    #[account(
+        seeds = ["config".as_bytes()],
+        bump = config.bump,
+        constraint = !config.paused @ CustomError::ProtocolPaused,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    pub metadata_program: Program<'info, Metadata>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn apply_update_proposal_handler(ctx: Context<ApplyUpdateProposal>) -> Result<()> {
+    let updates = ctx.accounts.proposal.updates.clone();
+
+    // The xNFT may have changed hands since the proposal was created,
+    // so the current owner or one of its delegates must be applying it.
+    verify_updater(
+        &ctx.accounts.xnft,
+        &ctx.accounts.master_token,
+        &ctx.accounts.master_metadata,
+        ctx.accounts.delegate.as_ref(),
+        ctx.accounts.updater.key,
+        updates.required_permissions(&ctx.accounts.xnft),
+    )?;
+
+    let content_hash = updates.content_hash;
+    let publish = updates.uri.is_some() || content_hash.is_some();
+
+    apply_updates(
+        &mut ctx.accounts.xnft,
+        &ctx.accounts.master_metadata,
+        &ctx.accounts.metadata_program,
+        ctx.remaining_accounts,
+        updates,
+    )?;
+
+    publish_release(
+        &mut ctx.accounts.xnft,
+        ctx.accounts.release.as_mut(),
+        ctx.bumps.get("release").copied(),
+        *ctx.accounts.updater.key,
+        publish,
+        content_hash,
+    )
+}
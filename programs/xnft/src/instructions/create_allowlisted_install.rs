@@ -0,0 +1,70 @@
+// Copyright (C) 2023 Blue Coral, Inc.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use anchor_lang::prelude::*;
+
+use crate::merkle;
+use crate::payments::*;
+use crate::state::AllowlistClaim;
+use crate::CustomError;
+
+#[derive(Accounts)]
+pub struct CreateAllowlistedInstall<'info> {
+    pub base: InstallAccounts<'info>,
+
+    #[account(
+        init,
+        payer = base.target,
+        space = AllowlistClaim::LEN,
+        seeds = [
+            "allowlist_claim".as_bytes(),
+            base.xnft.key().as_ref(),
+            base.target.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub claim: Account<'info, AllowlistClaim>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_allowlisted_install_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateAllowlistedInstall<'info>>,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let base = &mut ctx.accounts.base;
+
+    // The proof must lead from the target wallet to the allowlist root of the
+    // xNFT, with the initialization of the claim account preventing it from
+    // being used again by the same wallet.
+    let root = base
+        .xnft
+        .allowlist_root
+        .ok_or(error!(CustomError::AllowlistNotSet))?;
+
+    require!(
+        merkle::verify(&proof, &root, merkle::leaf(base.target.key)),
+        CustomError::InvalidAllowlistProof,
+    );
+
+    let claim = &mut ctx.accounts.claim;
+    **claim = AllowlistClaim::try_new(
+        *base.target.key,
+        base.xnft.key(),
+        *ctx.bumps.get("claim").unwrap(),
+    )?;
+
+    base.create(ctx.remaining_accounts, &ctx.bumps)
+}
@@ -21,8 +21,8 @@ use anchor_spl::metadata::{
 use anchor_spl::token::{self, FreezeAccount, Mint, MintTo, Token, TokenAccount};
 use mpl_token_metadata::state::{Creator, DataV2, MAX_URI_LENGTH};
 
-use crate::events::XnftCreated;
-use crate::state::{CreateXnftParams, Kind, Xnft};
+use crate::events::{ReleasePublished, XnftCreated};
+use crate::state::{Category, CreateXnftParams, Curator, Kind, ProtocolConfig, Release, Xnft};
 use crate::CustomError;
 
 #[derive(Accounts)]
@@ -76,10 +76,35 @@ pub struct CreateAppXnft<'info> {
     )]
     pub xnft: Box<Account<'info, Xnft>>,
 
+    #[account(
+        init,
+        payer = payer,
+        space = Release::LEN,
+        seeds = [
+            "release".as_bytes(),
+            xnft.key().as_ref(),
+            1_u32.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub release: Box<Account<'info, Release>>,
+
+    /// CHECK: is validated in the handler against the curator in the parameters,
+    /// which is not a `Curator` account if it is a wallet.
+    #[account(mut)]
+    pub curator: Option<UncheckedAccount<'info>>,
+
     #[account(mut)]
     pub payer: Signer<'info>,
     pub publisher: Signer<'info>,
 
+    #[account(
+        seeds = ["config".as_bytes()],
+        bump = config.bump,
+        constraint = !config.paused @ CustomError::ProtocolPaused,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
     pub system_program: Program<'info, System>,
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
@@ -166,6 +191,27 @@ pub fn create_app_xnft_handler(
         require_gt!(s, 0);
     }
 
+    // Check that if a subscription period was provided it is greater than 0.
+    if let Some(p) = params.subscription_period {
+        require_gt!(p, 0, CustomError::InvalidSubscriptionPeriod);
+    }
+
+    // Check that if a refund window was provided it is greater than 0.
+    if let Some(w) = params.refund_window {
+        require_gt!(w, 0, CustomError::InvalidRefundWindow);
+    }
+
+    // Check that every assigned category is in the registry.
+    Category::verify_registered(params.categories, ctx.remaining_accounts)?;
+
+    // Count the xNFT towards the curator it is assigned to.
+    if let Some(pubkey) = &params.curator {
+        Curator::assign(Curator::verify_assigned(
+            pubkey,
+            ctx.accounts.curator.as_deref(),
+        )?)?;
+    }
+
     // Initialize and populate the new xNFT program account data.
     let xnft = &mut ctx.accounts.xnft;
     ***xnft = Xnft::try_new(
@@ -178,6 +224,15 @@ pub fn create_app_xnft_handler(
         &params,
     )?;
 
+    // Record the initial URI of the xNFT as its first release.
+    let release = &mut ctx.accounts.release;
+    ***release = Release::try_new(
+        &mut ctx.accounts.xnft,
+        *ctx.accounts.publisher.key,
+        params.content_hash,
+        *ctx.bumps.get("release").unwrap(),
+    )?;
+
     // Mint the master token.
     token::mint_to(
         ctx.accounts
@@ -241,8 +296,15 @@ pub fn create_app_xnft_handler(
         ctx.accounts.update_primary_sale_happened_ctx(),
     )?;
 
+    emit!(ReleasePublished {
+        publisher: ctx.accounts.publisher.key(),
+        version: ctx.accounts.release.version,
+        xnft: ctx.accounts.xnft.key(),
+    });
+
     emit!(XnftCreated {
-        tag: params.tag,
+        categories: params.categories,
+        content_hash: params.content_hash,
         xnft: ctx.accounts.xnft.key(),
     });
 
@@ -0,0 +1,56 @@
+// Copyright (C) 2023 Blue Coral, Inc.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use anchor_lang::prelude::*;
+
+use crate::state::{Category, ProtocolConfig};
+use crate::CustomError;
+
+#[derive(Accounts)]
+#[instruction(id: u8)]
+pub struct CreateCategory<'info> {
+    #[account(
+        seeds = ["config".as_bytes()],
+        bump = config.bump,
+        has_one = admin @ CustomError::ProtocolAdminMismatch,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Auto derived below.
+    ////////////////////////////////////////////////////////////////////////////
+    #[account(
+        init,
+        payer = admin,
+        space = Category::LEN,
+        seeds = [
+            "category".as_bytes(),
+            &[id],
+        ],
+        bump,
+    )]
+    pub category: Account<'info, Category>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_category_handler(ctx: Context<CreateCategory>, id: u8, name: String) -> Result<()> {
+    let category = &mut ctx.accounts.category;
+    **category = Category::try_new(id, name, *ctx.bumps.get("category").unwrap())?;
+    Ok(())
+}
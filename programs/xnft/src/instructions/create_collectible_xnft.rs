@@ -18,7 +18,8 @@ use anchor_spl::metadata::MetadataAccount;
 use anchor_spl::token::{Mint, TokenAccount};
 use mpl_token_metadata::state::MAX_URI_LENGTH;
 
-use crate::state::{CreateXnftParams, Kind, Xnft};
+use crate::events::ReleasePublished;
+use crate::state::{Category, CreateXnftParams, Curator, Kind, ProtocolConfig, Release, Xnft};
 use crate::CustomError;
 
 #[derive(Accounts)]
@@ -53,10 +54,35 @@ pub struct CreateCollectibleXnft<'info> {
     )]
     pub xnft: Box<Account<'info, Xnft>>,
 
+    #[account(
+        init,
+        payer = payer,
+        space = Release::LEN,
+        seeds = [
+            "release".as_bytes(),
+            xnft.key().as_ref(),
+            1_u32.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub release: Box<Account<'info, Release>>,
+
+    /// CHECK: is validated in the handler against the curator in the parameters,
+    /// which is not a `Curator` account if it is a wallet.
+    #[account(mut)]
+    pub curator: Option<UncheckedAccount<'info>>,
+
     #[account(mut)]
     pub payer: Signer<'info>,
     pub publisher: Signer<'info>,
 
+    #[account(
+        seeds = ["config".as_bytes()],
+        bump = config.bump,
+        constraint = !config.paused @ CustomError::ProtocolPaused,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -70,6 +96,17 @@ pub fn create_collectible_xnft_handler(
         CustomError::UriExceedsMaxLength,
     );
 
+    // Check that every assigned category is in the registry.
+    Category::verify_registered(params.categories, ctx.remaining_accounts)?;
+
+    // Count the xNFT towards the curator it is assigned to.
+    if let Some(pubkey) = &params.curator {
+        Curator::assign(Curator::verify_assigned(
+            pubkey,
+            ctx.accounts.curator.as_deref(),
+        )?)?;
+    }
+
     // Instantiate and populate the xNFT program account data.
     let xnft = &mut ctx.accounts.xnft;
     ***xnft = Xnft::try_new(
@@ -82,5 +119,20 @@ pub fn create_collectible_xnft_handler(
         &params,
     )?;
 
+    // Record the initial URI of the xNFT as its first release.
+    let release = &mut ctx.accounts.release;
+    ***release = Release::try_new(
+        &mut ctx.accounts.xnft,
+        *ctx.accounts.publisher.key,
+        params.content_hash,
+        *ctx.bumps.get("release").unwrap(),
+    )?;
+
+    emit!(ReleasePublished {
+        publisher: ctx.accounts.publisher.key(),
+        version: ctx.accounts.release.version,
+        xnft: ctx.accounts.xnft.key(),
+    });
+
     Ok(())
 }
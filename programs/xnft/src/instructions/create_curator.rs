@@ -0,0 +1,70 @@
+// Copyright (C) 2023 Blue Coral, Inc.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use anchor_lang::prelude::*;
+
+use crate::state::{Curator, ProtocolConfig};
+use crate::CustomError;
+
+#[derive(Accounts)]
+#[instruction(name: String)]
+pub struct CreateCurator<'info> {
+    ////////////////////////////////////////////////////////////////////////////
+    // Auto derived below.
+    ////////////////////////////////////////////////////////////////////////////
+    #[account(
+        init,
+        payer = authority,
+        space = Curator::LEN,
+        seeds = [
+            "curator".as_bytes(),
+            name.as_bytes(),
+        ],
+        bump,
+    )]
+    pub curator: Account<'info, Curator>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = ["config".as_bytes()],
+        bump = config.bump,
+        constraint = !config.paused @ CustomError::ProtocolPaused,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_curator_handler(
+    ctx: Context<CreateCurator>,
+    name: String,
+    uri: String,
+    members: Vec<Pubkey>,
+) -> Result<()> {
+    // The argued name does not need to be validated since the maximum
+    // seed length already limits it to 32 bytes.
+    let curator = &mut ctx.accounts.curator;
+    **curator = Curator::try_new(
+        *ctx.accounts.authority.key,
+        name,
+        uri,
+        members,
+        *ctx.bumps.get("curator").unwrap(),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,89 @@
+// Copyright (C) 2023 Blue Coral, Inc.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use anchor_lang::prelude::*;
+use anchor_spl::metadata::MetadataAccount;
+use anchor_spl::token::TokenAccount;
+
+use super::verify_updater;
+use crate::state::{Delegate, ProtocolConfig, Xnft};
+use crate::CustomError;
+
+#[derive(Accounts)]
+pub struct CreateDelegate<'info> {
+    #[account(has_one = master_metadata)]
+    pub xnft: Account<'info, Xnft>,
+
+    #[account(
+        constraint = master_token.mint == xnft.master_mint,
+        constraint = master_token.amount == 1,
+    )]
+    pub master_token: Account<'info, TokenAccount>,
+
+    pub master_metadata: Account<'info, MetadataAccount>,
+
+    /// CHECK: validation is not required for the wallet being delegated to.
+    pub wallet: UncheckedAccount<'info>,
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Auto derived below.
+    ////////////////////////////////////////////////////////////////////////////
+    #[account(
+        init,
+        payer = authority,
+        space = Delegate::LEN,
+        seeds = [
+            "delegate".as_bytes(),
+            xnft.key().as_ref(),
+            wallet.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub delegate: Account<'info, Delegate>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = ["config".as_bytes()],
+        bump = config.bump,
+        constraint = !config.paused @ CustomError::ProtocolPaused,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_delegate_handler(ctx: Context<CreateDelegate>, permissions: u8) -> Result<()> {
+    verify_updater(
+        &ctx.accounts.xnft,
+        &ctx.accounts.master_token,
+        &ctx.accounts.master_metadata,
+        None,
+        ctx.accounts.authority.key,
+        0,
+    )?;
+
+    let delegate = &mut ctx.accounts.delegate;
+    **delegate = Delegate::try_new(
+        ctx.accounts.xnft.key(),
+        *ctx.accounts.wallet.key,
+        *ctx.accounts.authority.key,
+        permissions,
+        *ctx.bumps.get("delegate").unwrap(),
+    )?;
+
+    Ok(())
+}
@@ -14,77 +14,31 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use anchor_lang::prelude::*;
-use anchor_lang::system_program;
+use anchor_spl::metadata::MetadataAccount;
+use anchor_spl::token_interface::TokenAccount;
 
-use crate::events::InstallationCreated;
-use crate::state::{Install, Kind, Xnft};
-use crate::CustomError;
+use crate::payments::*;
 
 #[derive(Accounts)]
 pub struct CreateInstall<'info> {
-    #[account(
-        mut,
-        has_one = install_vault,
-        constraint = xnft.kind == Kind::App @ CustomError::MustBeApp,
-        constraint = !xnft.suspended @ CustomError::SuspendedInstallation,
-    )]
-    pub xnft: Account<'info, Xnft>,
+    pub base: InstallAccounts<'info>,
 
-    /// CHECK: xnft has_one constraint.
-    #[account(mut)]
-    pub install_vault: UncheckedAccount<'info>,
+    pub gate_token: Option<InterfaceAccount<'info, TokenAccount>>,
 
-    ////////////////////////////////////////////////////////////////////////////
-    // Auto derived below.
-    ////////////////////////////////////////////////////////////////////////////
-    #[account(
-        init,
-        payer = target,
-        space = Install::LEN,
-        seeds = [
-            "install".as_bytes(),
-            target.key().as_ref(),
-            xnft.key().as_ref(),
-        ],
-        bump,
-    )]
-    pub install: Account<'info, Install>,
-
-    #[account(mut)]
-    pub target: Signer<'info>,
-    pub authority: Signer<'info>,
-
-    pub system_program: Program<'info, System>,
+    pub gate_metadata: Option<Box<Account<'info, MetadataAccount>>>,
 }
 
-pub fn create_install_handler(ctx: Context<CreateInstall>) -> Result<()> {
-    let xnft = &mut ctx.accounts.xnft;
-    let install = &mut ctx.accounts.install;
-
-    xnft.verify_supply()?;
-    xnft.verify_install_authority(ctx.accounts.authority.key)?;
-
-    // Pay to install the xNFT, if needed.
-    if xnft.install_price > 0 {
-        system_program::transfer(
-            CpiContext::new(
-                ctx.accounts.system_program.to_account_info(),
-                system_program::Transfer {
-                    from: ctx.accounts.authority.to_account_info(),
-                    to: ctx.accounts.install_vault.to_account_info(),
-                },
-            ),
-            xnft.install_price,
-        )?;
-    }
-
-    // Initialize the install data.
-    **install = Install::new(xnft, ctx.accounts.target.key);
+pub fn create_install_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateInstall<'info>>,
+) -> Result<()> {
+    let base = &mut ctx.accounts.base;
 
-    emit!(InstallationCreated {
-        installer: ctx.accounts.target.key(),
-        xnft: xnft.key(),
-    });
+    base.xnft.verify_install_authority(base.authority.key)?;
+    base.xnft.verify_install_gate(
+        base.target.key,
+        ctx.accounts.gate_token.as_deref().map(|t| &**t),
+        ctx.accounts.gate_metadata.as_deref().map(|m| &***m),
+    )?;
 
-    Ok(())
+    base.create(ctx.remaining_accounts, &ctx.bumps)
 }
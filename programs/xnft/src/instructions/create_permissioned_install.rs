@@ -14,98 +14,53 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use anchor_lang::prelude::*;
-use anchor_lang::system_program;
 
-use crate::events::InstallationCreated;
-use crate::state::{Access, Install, Kind, Xnft};
+use crate::payments::*;
+use crate::state::Access;
 use crate::CustomError;
 
 #[derive(Accounts)]
 pub struct CreatePermissionedInstall<'info> {
-    #[account(
-        mut,
-        has_one = install_vault,
-        constraint = xnft.kind == Kind::App @ CustomError::MustBeApp,
-        constraint = !xnft.suspended @ CustomError::SuspendedInstallation,
-        constraint = xnft.install_authority == Some(*install_authority.key) @ CustomError::InstallAuthorityMismatch,
-    )]
-    pub xnft: Account<'info, Xnft>,
-
-    /// CHECK: xnft has_one constraint
-    #[account(mut)]
-    pub install_vault: UncheckedAccount<'info>,
-
-    ////////////////////////////////////////////////////////////////////////////
-    // Auto derived below.
-    ////////////////////////////////////////////////////////////////////////////
-    #[account(
-        init,
-        payer = authority,
-        space = Install::LEN,
-        seeds = [
-            "install".as_bytes(),
-            authority.key().as_ref(),
-            xnft.key().as_ref(),
-        ],
-        bump,
-    )]
-    pub install: Account<'info, Install>,
+    pub base: InstallAccounts<'info>,
 
     #[account(
         mut,
-        close = install_authority,
         seeds = [
             "access".as_bytes(),
-            authority.key().as_ref(),
-            xnft.key().as_ref(),
+            base.target.key().as_ref(),
+            base.xnft.key().as_ref(),
         ],
         bump = access.bump,
-        has_one = xnft,
-        constraint = access.wallet == *authority.key @ CustomError::UnauthorizedInstall,
+        constraint = access.xnft == base.xnft.key() @ CustomError::UnauthorizedInstall,
+        constraint = access.wallet == base.target.key() @ CustomError::UnauthorizedInstall,
     )]
     pub access: Account<'info, Access>,
 
-    /// CHECK: account matching constraint on the `xnft` account.
-    #[account(mut)]
+    /// CHECK: account matching constraint on the `xnft` account and receiver
+    /// of the rent of the `access` account once its installs are used up.
+    #[account(
+        mut,
+        constraint = base.xnft.install_authority == Some(*install_authority.key) @ CustomError::InstallAuthorityMismatch,
+    )]
     pub install_authority: UncheckedAccount<'info>,
-
-    #[account(mut)]
-    pub authority: Signer<'info>,
-
-    pub system_program: Program<'info, System>,
 }
 
-pub fn create_permissioned_install_handler(ctx: Context<CreatePermissionedInstall>) -> Result<()> {
-    let xnft = &mut ctx.accounts.xnft;
-    let install = &mut ctx.accounts.install;
-
+pub fn create_permissioned_install_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreatePermissionedInstall<'info>>,
+) -> Result<()> {
     // No validation of the install authority is necessary here. The existence of
     // an accepted `access` account that passed the constraints asserts that the
-    // signing wallet does in fact have whitelisted permission to install this xNFT
+    // target wallet does in fact have whitelisted permission to install this xNFT
     // regardless of the state of it's `install_authority`.
-    xnft.verify_supply()?;
+    //
+    // The access account is kept open for reinstalls until its quota has been
+    // used up, at which point the rent is returned to the install authority.
+    let access = &mut ctx.accounts.access;
+    access.use_install(Clock::get()?.unix_timestamp)?;
 
-    // Pay to install the xNFT, if needed.
-    if xnft.install_price > 0 {
-        system_program::transfer(
-            CpiContext::new(
-                ctx.accounts.system_program.to_account_info(),
-                system_program::Transfer {
-                    from: ctx.accounts.authority.to_account_info(),
-                    to: ctx.accounts.install_vault.to_account_info(),
-                },
-            ),
-            xnft.install_price,
-        )?;
+    if access.is_exhausted() {
+        access.close(ctx.accounts.install_authority.to_account_info())?;
     }
 
-    // Initialize the install data.
-    **install = Install::new(xnft, ctx.accounts.authority.key);
-
-    emit!(InstallationCreated {
-        installer: ctx.accounts.authority.key(),
-        xnft: xnft.key(),
-    });
-
-    Ok(())
+    ctx.accounts.base.create(ctx.remaining_accounts, &ctx.bumps)
 }
@@ -17,7 +17,7 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::TokenAccount;
 
 use crate::events::ReviewCreated;
-use crate::state::{Install, Kind, Review, Xnft};
+use crate::state::{Install, Kind, ProtocolConfig, Review, Xnft};
 use crate::{CustomError, MAX_RATING, MIN_RATING};
 
 #[derive(Accounts)]
@@ -49,7 +49,7 @@ pub struct CreateReview<'info> {
     #[account(
         init,
         payer = author,
-        space = Review::len(uri),
+        space = Review::len(&uri),
         seeds = [
             "review".as_bytes(),
             xnft.key().as_ref(),
@@ -62,6 +62,13 @@ pub struct CreateReview<'info> {
     #[account(mut)]
     pub author: Signer<'info>,
 
+    #[account(
+        seeds = ["config".as_bytes()],
+        bump = config.bump,
+        constraint = !config.paused @ CustomError::ProtocolPaused,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
     pub system_program: Program<'info, System>,
 }
 
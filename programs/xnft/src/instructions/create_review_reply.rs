@@ -0,0 +1,82 @@
+// Copyright (C) 2023 Blue Coral, Inc.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::events::ReviewReplyCreated;
+use crate::state::{ProtocolConfig, Review, ReviewReply, Xnft};
+use crate::CustomError;
+
+#[derive(Accounts)]
+#[instruction(uri: String)]
+pub struct CreateReviewReply<'info> {
+    #[account(has_one = xnft)]
+    pub review: Account<'info, Review>,
+
+    pub xnft: Account<'info, Xnft>,
+
+    #[account(
+        associated_token::mint = xnft.master_mint,
+        associated_token::authority = authority,
+        constraint = master_token.amount == 1,
+    )]
+    pub master_token: Account<'info, TokenAccount>,
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Auto derived below.
+    ////////////////////////////////////////////////////////////////////////////
+    #[account(
+        init,
+        payer = authority,
+        space = ReviewReply::len(&uri),
+        seeds = [
+            "reply".as_bytes(),
+            review.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub reply: Account<'info, ReviewReply>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = ["config".as_bytes()],
+        bump = config.bump,
+        constraint = !config.paused @ CustomError::ProtocolPaused,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_review_reply_handler(ctx: Context<CreateReviewReply>, uri: String) -> Result<()> {
+    let reply = &mut ctx.accounts.reply;
+    **reply = ReviewReply::try_new(
+        ctx.accounts.review.key(),
+        ctx.accounts.xnft.key(),
+        ctx.accounts.authority.key(),
+        uri,
+        *ctx.bumps.get("reply").unwrap(),
+    )?;
+
+    emit!(ReviewReplyCreated {
+        author: reply.author,
+        review: reply.review,
+        xnft: reply.xnft,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,89 @@
+// Copyright (C) 2023 Blue Coral, Inc.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar;
+
+use crate::payments::*;
+use crate::state::{InstallVoucher, VoucherNonce};
+use crate::{vouchers, CustomError};
+
+#[derive(Accounts)]
+#[instruction(voucher: InstallVoucher)]
+pub struct CreateVoucherInstall<'info> {
+    pub base: InstallAccounts<'info>,
+
+    #[account(
+        init,
+        payer = base.target,
+        space = VoucherNonce::LEN,
+        seeds = [
+            "voucher_nonce".as_bytes(),
+            base.xnft.key().as_ref(),
+            &voucher.nonce.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub nonce: Account<'info, VoucherNonce>,
+
+    /// CHECK: address constraint to the instructions sysvar.
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_voucher_install_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateVoucherInstall<'info>>,
+    voucher: InstallVoucher,
+) -> Result<()> {
+    let base = &mut ctx.accounts.base;
+
+    // The voucher must have been issued to the target wallet for this xNFT and
+    // signed by its install authority in the preceding Ed25519 instruction, with
+    // the initialization of the nonce account preventing it from being replayed.
+    let install_authority = base
+        .xnft
+        .install_authority
+        .ok_or(error!(CustomError::InstallAuthorityMismatch))?;
+
+    require_keys_eq!(
+        voucher.wallet,
+        base.target.key(),
+        CustomError::VoucherMismatch,
+    );
+    require_keys_eq!(voucher.xnft, base.xnft.key(), CustomError::VoucherMismatch);
+    require_gt!(
+        voucher.expires_ts,
+        Clock::get()?.unix_timestamp,
+        CustomError::VoucherExpired,
+    );
+
+    vouchers::verify_preceding(
+        &ctx.accounts.instructions,
+        &install_authority,
+        &voucher.message(),
+    )?;
+
+    let nonce = &mut ctx.accounts.nonce;
+    **nonce = VoucherNonce::try_new(
+        base.xnft.key(),
+        *base.target.key,
+        voucher.nonce,
+        *ctx.bumps.get("nonce").unwrap(),
+    )?;
+
+    base.create(ctx.remaining_accounts, &ctx.bumps)
+}
@@ -0,0 +1,40 @@
+// Copyright (C) 2023 Blue Coral, Inc.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use anchor_lang::prelude::*;
+
+use crate::state::Curator;
+use crate::CustomError;
+
+#[derive(Accounts)]
+pub struct DeleteCurator<'info> {
+    #[account(
+        mut,
+        close = receiver,
+        has_one = authority,
+        constraint = curator.total_xnfts == 0 @ CustomError::CuratorInUse,
+    )]
+    pub curator: Account<'info, Curator>,
+
+    /// CHECK: the account receiving the rent doesn't need validation.
+    #[account(mut)]
+    pub receiver: UncheckedAccount<'info>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn delete_curator_handler(_ctx: Context<DeleteCurator>) -> Result<()> {
+    Ok(())
+}
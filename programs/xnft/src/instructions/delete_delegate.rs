@@ -0,0 +1,64 @@
+// Copyright (C) 2023 Blue Coral, Inc.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use anchor_lang::prelude::*;
+use anchor_spl::metadata::MetadataAccount;
+use anchor_spl::token::TokenAccount;
+
+use super::verify_updater;
+use crate::state::{Delegate, Xnft};
+
+#[derive(Accounts)]
+pub struct DeleteDelegate<'info> {
+    #[account(has_one = master_metadata)]
+    pub xnft: Account<'info, Xnft>,
+
+    #[account(
+        constraint = master_token.mint == xnft.master_mint,
+        constraint = master_token.amount == 1,
+    )]
+    pub master_token: Account<'info, TokenAccount>,
+
+    pub master_metadata: Account<'info, MetadataAccount>,
+
+    #[account(
+        mut,
+        close = receiver,
+        has_one = xnft,
+    )]
+    pub delegate: Account<'info, Delegate>,
+
+    /// CHECK: the account receiving the rent doesn't need validation.
+    #[account(mut)]
+    pub receiver: UncheckedAccount<'info>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn delete_delegate_handler(ctx: Context<DeleteDelegate>) -> Result<()> {
+    // A delegated wallet is able to renounce its own permissions.
+    if ctx.accounts.delegate.wallet == *ctx.accounts.authority.key {
+        return Ok(());
+    }
+
+    verify_updater(
+        &ctx.accounts.xnft,
+        &ctx.accounts.master_token,
+        &ctx.accounts.master_metadata,
+        None,
+        ctx.accounts.authority.key,
+        0,
+    )
+}
@@ -15,7 +15,8 @@
 
 use anchor_lang::prelude::*;
 
-use crate::state::{Review, Xnft};
+use crate::state::{Review, ReviewReply, Xnft};
+use crate::CustomError;
 
 #[derive(Accounts)]
 pub struct DeleteReview<'info> {
@@ -24,9 +25,17 @@ pub struct DeleteReview<'info> {
         close = receiver,
         has_one = author,
         has_one = xnft,
+        constraint = !review.hidden @ CustomError::ReviewHidden,
     )]
     pub review: Account<'info, Review>,
 
+    #[account(mut, has_one = review)]
+    pub reply: Option<Account<'info, ReviewReply>>,
+
+    /// CHECK: is validated against the author of the `reply` to refund its rent.
+    #[account(mut)]
+    pub reply_author: Option<UncheckedAccount<'info>>,
+
     #[account(mut)]
     pub xnft: Account<'info, Xnft>,
 
@@ -41,7 +50,10 @@ pub fn delete_review_handler(ctx: Context<DeleteReview>) -> Result<()> {
     let xnft = &mut ctx.accounts.xnft;
     let review = &ctx.accounts.review;
 
-    xnft.num_ratings -= 1;
-    xnft.total_rating -= std::convert::TryInto::<u64>::try_into(review.rating).unwrap();
-    Ok(())
+    xnft.remove_rating(review.rating);
+
+    ReviewReply::close_with_review(
+        ctx.accounts.reply.as_ref(),
+        ctx.accounts.reply_author.as_ref(),
+    )
 }
@@ -0,0 +1,55 @@
+// Copyright (C) 2023 Blue Coral, Inc.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::events::ReviewReplyDeleted;
+use crate::state::{ReviewReply, Xnft};
+
+#[derive(Accounts)]
+pub struct DeleteReviewReply<'info> {
+    #[account(
+        mut,
+        close = receiver,
+        has_one = xnft,
+    )]
+    pub reply: Account<'info, ReviewReply>,
+
+    pub xnft: Account<'info, Xnft>,
+
+    #[account(
+        associated_token::mint = xnft.master_mint,
+        associated_token::authority = authority,
+        constraint = master_token.amount == 1,
+    )]
+    pub master_token: Account<'info, TokenAccount>,
+
+    /// CHECK: the account receiving the rent doesn't need validation.
+    #[account(mut)]
+    pub receiver: UncheckedAccount<'info>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn delete_review_reply_handler(ctx: Context<DeleteReviewReply>) -> Result<()> {
+    let reply = &ctx.accounts.reply;
+
+    emit!(ReviewReplyDeleted {
+        review: reply.review,
+        xnft: reply.xnft,
+    });
+
+    Ok(())
+}
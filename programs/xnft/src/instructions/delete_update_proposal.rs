@@ -0,0 +1,57 @@
+// Copyright (C) 2023 Blue Coral, Inc.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::state::{UpdateProposal, Xnft};
+use crate::CustomError;
+
+#[derive(Accounts)]
+pub struct DeleteUpdateProposal<'info> {
+    #[account(
+        mut,
+        close = proposer,
+        has_one = proposer,
+        has_one = xnft,
+    )]
+    pub proposal: Account<'info, UpdateProposal>,
+
+    pub xnft: Account<'info, Xnft>,
+
+    #[account(
+        associated_token::mint = xnft.master_mint,
+        associated_token::authority = authority,
+        constraint = master_token.amount == 1,
+    )]
+    pub master_token: Option<Account<'info, TokenAccount>>,
+
+    /// CHECK: is validated by the `has_one` constraint on `proposal` to refund the rent.
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn delete_update_proposal_handler(ctx: Context<DeleteUpdateProposal>) -> Result<()> {
+    // The proposal can be withdrawn by the wallet that submitted it, even if it
+    // no longer owns the xNFT, or closed by the current owner of the xNFT.
+    require!(
+        ctx.accounts.authority.key() == ctx.accounts.proposer.key()
+            || ctx.accounts.master_token.is_some(),
+        CustomError::UpdateAuthorityMismatch,
+    );
+    Ok(())
+}
@@ -17,7 +17,7 @@ use anchor_lang::prelude::*;
 use anchor_spl::metadata::MetadataAccount;
 use anchor_spl::token::{burn, close_account, Burn, CloseAccount, Token, TokenAccount};
 
-use crate::state::{Kind, Xnft};
+use crate::state::{Curator, Kind, Xnft};
 use crate::CustomError;
 
 #[derive(Accounts)]
@@ -54,6 +54,11 @@ pub struct DeleteXnft<'info> {
     #[account(mut)]
     pub receiver: UncheckedAccount<'info>,
 
+    /// CHECK: is validated in the handler against the curator of the xNFT,
+    /// which is not a `Curator` account if it is a wallet.
+    #[account(mut)]
+    pub curator: Option<UncheckedAccount<'info>>,
+
     pub authority: Signer<'info>,
 
     pub token_program: Program<'info, Token>,
@@ -98,5 +103,13 @@ pub fn delete_xnft_handler(ctx: Context<DeleteXnft>) -> Result<()> {
         close_account(ctx.accounts.close_ata_ctx())?;
     }
 
+    // The curator no longer counts the deleted xNFT.
+    if let Some(status) = &xnft.curator {
+        Curator::unassign(Curator::verify_assigned(
+            &status.pubkey,
+            ctx.accounts.curator.as_deref(),
+        )?)?;
+    }
+
     Ok(())
 }
@@ -17,7 +17,7 @@ use anchor_lang::prelude::*;
 use anchor_lang::system_program;
 use anchor_spl::metadata::MetadataAccount;
 
-use crate::state::{Kind, Xnft};
+use crate::state::{Kind, ProtocolConfig, Xnft};
 use crate::CustomError;
 
 #[derive(Accounts)]
@@ -30,6 +30,17 @@ pub struct Donate<'info> {
 
     pub master_metadata: Account<'info, MetadataAccount>,
 
+    #[account(
+        seeds = ["config".as_bytes()],
+        bump = config.bump,
+        constraint = !config.paused @ CustomError::ProtocolPaused,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    /// CHECK: config address constraint.
+    #[account(mut, address = config.treasury)]
+    pub treasury: UncheckedAccount<'info>,
+
     #[account(mut)]
     pub donator: Signer<'info>,
 
@@ -50,6 +61,23 @@ pub fn donate_handler<'info>(
     let creators = metadata.data.creators.as_ref().unwrap();
     require_eq!(creators.len(), ctx.remaining_accounts.len());
 
+    // Take the protocol fee into the treasury before splitting the remaining
+    // amount of the donation across the creators.
+    let fee = ctx.accounts.config.fee_for(amount);
+    if fee > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.donator.to_account_info(),
+                    to: ctx.accounts.treasury.to_account_info(),
+                },
+            ),
+            fee,
+        )?;
+    }
+
+    let amount = amount - fee;
     let mut available = amount;
 
     for (i, c) in creators.iter().enumerate() {
@@ -14,19 +14,36 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
 
+use super::verify_owner_or_delegate;
 use crate::events::AccessGranted;
-use crate::state::{Access, Kind, Xnft};
-use crate::CustomError;
+use crate::state::{Access, Delegate, Kind, ProtocolConfig, Xnft};
+use crate::{CustomError, PERMISSION_MANAGE_ACCESS};
 
 #[derive(Accounts)]
 pub struct GrantAccess<'info> {
     #[account(
-        constraint = xnft.install_authority == Some(*authority.key),
         constraint = xnft.kind == Kind::App @ CustomError::MustBeApp,
     )]
     pub xnft: Account<'info, Xnft>,
 
+    #[account(
+        seeds = [
+            "delegate".as_bytes(),
+            xnft.key().as_ref(),
+            authority.key().as_ref(),
+        ],
+        bump = delegate.bump,
+    )]
+    pub delegate: Option<Account<'info, Delegate>>,
+
+    #[account(
+        constraint = master_token.mint == xnft.master_mint,
+        constraint = master_token.amount == 1,
+    )]
+    pub master_token: Option<Account<'info, TokenAccount>>,
+
     /// CHECK: validation is not required for wallet being granted access.
     pub wallet: UncheckedAccount<'info>,
 
@@ -49,20 +66,50 @@ pub struct GrantAccess<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
 
+    #[account(
+        seeds = ["config".as_bytes()],
+        bump = config.bump,
+        constraint = !config.paused @ CustomError::ProtocolPaused,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
     pub system_program: Program<'info, System>,
 }
 
-pub fn grant_access_handler(ctx: Context<GrantAccess>) -> Result<()> {
+pub fn grant_access_handler(
+    ctx: Context<GrantAccess>,
+    expires_ts: Option<i64>,
+    max_installs: Option<u32>,
+) -> Result<()> {
+    let install_authority = ctx
+        .accounts
+        .xnft
+        .install_authority
+        .ok_or(error!(CustomError::InstallAuthorityMismatch))?;
+
+    verify_owner_or_delegate(
+        &install_authority,
+        ctx.accounts.authority.key,
+        ctx.accounts.delegate.as_ref(),
+        ctx.accounts.master_token.as_ref().map(|t| &t.owner),
+        PERMISSION_MANAGE_ACCESS,
+        CustomError::InstallAuthorityMismatch,
+    )?;
+
     let access = &mut ctx.accounts.access;
     let xnft = &ctx.accounts.xnft;
 
-    **access = Access::new(
+    **access = Access::try_new(
         *ctx.accounts.wallet.key,
         xnft.key(),
         *ctx.bumps.get("access").unwrap(),
-    );
+        expires_ts,
+        max_installs,
+    )?;
 
     emit!(AccessGranted {
+        expires_ts,
+        max_installs,
         wallet: *ctx.accounts.wallet.key,
         xnft: xnft.key(),
     });
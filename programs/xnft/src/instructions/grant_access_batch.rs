@@ -0,0 +1,224 @@
+// Copyright (C) 2023 Blue Coral, Inc.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use super::verify_owner_or_delegate;
+use crate::events::AccessGranted;
+use crate::pda;
+use crate::state::{Access, Delegate, Kind, ProtocolConfig, Xnft};
+use crate::{CustomError, PERMISSION_MANAGE_ACCESS};
+
+#[derive(Accounts)]
+pub struct GrantAccessBatch<'info> {
+    #[account(
+        constraint = xnft.kind == Kind::App @ CustomError::MustBeApp,
+    )]
+    pub xnft: Account<'info, Xnft>,
+
+    #[account(
+        seeds = [
+            "delegate".as_bytes(),
+            xnft.key().as_ref(),
+            authority.key().as_ref(),
+        ],
+        bump = delegate.bump,
+    )]
+    pub delegate: Option<Account<'info, Delegate>>,
+
+    #[account(
+        constraint = master_token.mint == xnft.master_mint,
+        constraint = master_token.amount == 1,
+    )]
+    pub master_token: Option<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = ["config".as_bytes()],
+        bump = config.bump,
+        constraint = !config.paused @ CustomError::ProtocolPaused,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn grant_access_batch_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, GrantAccessBatch<'info>>,
+    wallets: Vec<Pubkey>,
+    expires_ts: Option<i64>,
+    max_installs: Option<u32>,
+) -> Result<()> {
+    let install_authority = ctx
+        .accounts
+        .xnft
+        .install_authority
+        .ok_or(error!(CustomError::InstallAuthorityMismatch))?;
+
+    verify_owner_or_delegate(
+        &install_authority,
+        ctx.accounts.authority.key,
+        ctx.accounts.delegate.as_ref(),
+        ctx.accounts.master_token.as_ref().map(|t| &t.owner),
+        PERMISSION_MANAGE_ACCESS,
+        CustomError::InstallAuthorityMismatch,
+    )?;
+
+    require_eq!(
+        ctx.remaining_accounts.len(),
+        wallets.len(),
+        CustomError::AccessAccountMismatch,
+    );
+
+    let xnft = ctx.accounts.xnft.key();
+
+    // Each remaining account must be the access PDA of the wallet at the same
+    // index, which is created and initialized as it would be by `grant_access`.
+    for (wallet, info) in wallets.iter().zip(ctx.remaining_accounts) {
+        let (address, bump) = Pubkey::find_program_address(
+            &["access".as_bytes(), wallet.as_ref(), xnft.as_ref()],
+            ctx.program_id,
+        );
+        require_keys_eq!(*info.key, address, CustomError::AccessAccountMismatch);
+
+        create_access(
+            &ctx.accounts.authority,
+            &ctx.accounts.system_program,
+            info,
+            Access::try_new(*wallet, xnft, bump, expires_ts, max_installs)?,
+            ctx.program_id,
+        )?;
+
+        emit!(AccessGranted {
+            expires_ts,
+            max_installs,
+            wallet: *wallet,
+            xnft,
+        });
+    }
+
+    Ok(())
+}
+
+/// Creates the access PDA, which may already hold lamports, and writes the
+/// access into it with its account discriminator.
+fn create_access<'info>(
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    info: &AccountInfo<'info>,
+    access: Access,
+    program_id: &Pubkey,
+) -> Result<()> {
+    pda::create(
+        payer,
+        info,
+        system_program,
+        Access::LEN,
+        program_id,
+        &[
+            "access".as_bytes(),
+            access.wallet.as_ref(),
+            access.xnft.as_ref(),
+            &[access.bump],
+        ],
+    )?;
+
+    let mut account = Account::<Access>::try_from_unchecked(info)?;
+    account.set_inner(access);
+    account.exit(program_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::solana_program::system_instruction;
+
+    use super::*;
+    use crate::pda::stubs;
+
+    #[test]
+    fn grants_access_to_prefunded_account() {
+        stubs::install();
+
+        let payer_key = Pubkey::new_unique();
+        let system_key = anchor_lang::system_program::ID;
+        let wallet = Pubkey::new_unique();
+        let xnft = Pubkey::new_unique();
+        let (key, bump) = Pubkey::find_program_address(
+            &["access".as_bytes(), wallet.as_ref(), xnft.as_ref()],
+            &crate::ID,
+        );
+
+        let (mut payer_lamports, mut system_lamports, mut lamports) = (1_000_000_000, 0, 1);
+        let (mut payer_data, mut system_data) = ([], []);
+        // The stubbed system program does not allocate or assign the account.
+        let mut data = [0; Access::LEN];
+
+        let payer = AccountInfo::new(
+            &payer_key,
+            true,
+            true,
+            &mut payer_lamports,
+            &mut payer_data,
+            &system_key,
+            false,
+            0,
+        );
+        let system = AccountInfo::new(
+            &system_key,
+            false,
+            false,
+            &mut system_lamports,
+            &mut system_data,
+            &system_key,
+            true,
+            0,
+        );
+        let info = AccountInfo::new(
+            &key,
+            false,
+            true,
+            &mut lamports,
+            &mut data,
+            &crate::ID,
+            false,
+            0,
+        );
+
+        let access = Access::try_new(wallet, xnft, bump, None, Some(2)).unwrap();
+        create_access(&payer, &system, &info, access, &crate::ID).unwrap();
+
+        assert_eq!(
+            stubs::invoked(),
+            vec![
+                system_instruction::transfer(
+                    &payer_key,
+                    &key,
+                    Rent::default().minimum_balance(Access::LEN) - 1,
+                ),
+                system_instruction::allocate(&key, Access::LEN as u64),
+                system_instruction::assign(&key, &crate::ID),
+            ],
+        );
+
+        let access = Account::<Access>::try_from(&info).unwrap();
+        assert_eq!(access.wallet, wallet);
+        assert_eq!(access.xnft, xnft);
+        assert_eq!(access.bump, bump);
+        assert_eq!(access.max_installs, 2);
+    }
+}
@@ -0,0 +1,76 @@
+// Copyright (C) 2023 Blue Coral, Inc.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use anchor_lang::prelude::*;
+
+use crate::program::Xnft;
+use crate::state::ProtocolConfig;
+use crate::{CustomError, MAX_FEE_BASIS_POINTS};
+
+#[derive(Accounts)]
+pub struct InitializeProtocolConfig<'info> {
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key()),
+    )]
+    pub program: Program<'info, Xnft>,
+
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(*authority.key) @ CustomError::ProtocolAdminMismatch,
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    /// CHECK: validation is not required for the account receiving fees.
+    pub treasury: UncheckedAccount<'info>,
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Auto derived below.
+    ////////////////////////////////////////////////////////////////////////////
+    #[account(
+        init,
+        payer = authority,
+        space = ProtocolConfig::LEN,
+        seeds = ["config".as_bytes()],
+        bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_protocol_config_handler(
+    ctx: Context<InitializeProtocolConfig>,
+    fee_basis_points: u16,
+) -> Result<()> {
+    require_gte!(
+        MAX_FEE_BASIS_POINTS,
+        fee_basis_points,
+        CustomError::FeeExceedsMaximum,
+    );
+
+    // The upgrade authority of the program is the initial admin of the
+    // protocol, which can then be rotated to another wallet.
+    let config = &mut ctx.accounts.config;
+    **config = ProtocolConfig::new(
+        *ctx.accounts.authority.key,
+        *ctx.accounts.treasury.key,
+        fee_basis_points,
+        *ctx.bumps.get("config").unwrap(),
+    );
+
+    Ok(())
+}
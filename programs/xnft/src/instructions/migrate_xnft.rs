@@ -0,0 +1,78 @@
+// Copyright (C) 2023 Blue Coral, Inc.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use anchor_lang::prelude::*;
+use anchor_lang::{system_program, Discriminator};
+
+use crate::state::Xnft;
+use crate::CustomError;
+
+#[derive(Accounts)]
+pub struct MigrateXnft<'info> {
+    /// CHECK: is validated in the handler, since an account with a previous
+    /// layout cannot be deserialized until it has been reallocated.
+    #[account(
+        mut,
+        owner = crate::ID,
+    )]
+    pub xnft: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_xnft_handler(ctx: Context<MigrateXnft>) -> Result<()> {
+    let info = ctx.accounts.xnft.to_account_info();
+
+    // Only program accounts with the xNFT discriminator can be migrated.
+    require!(
+        info.try_borrow_data()?.get(..8) == Some(&Xnft::discriminator()[..]),
+        ErrorCode::AccountDiscriminatorMismatch,
+    );
+    let grow = info.data_len() < Xnft::LEN;
+
+    if grow {
+        // Top up the rent exemption of the account for its new size before
+        // growing it, with the new fields of the layout zero-initialized.
+        let lamports = Rent::get()?
+            .minimum_balance(Xnft::LEN)
+            .saturating_sub(info.lamports());
+
+        if lamports > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.payer.to_account_info(),
+                        to: info.clone(),
+                    },
+                ),
+                lamports,
+            )?;
+        }
+
+        info.realloc(Xnft::LEN, true)?;
+    }
+
+    // Ensure that the migrated account data deserializes into the current layout
+    // and carry over a tag set before the category registry replaced it.
+    let mut xnft = Account::<Xnft>::try_from(&info)?;
+    let retagged = xnft.migrate_legacy_tag();
+    require!(grow || retagged, CustomError::XnftAlreadyMigrated);
+
+    xnft.exit(ctx.program_id)
+}
@@ -13,36 +13,110 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
+mod accept_protocol_admin;
+mod apply_update_proposal;
+mod create_allowlisted_install;
 mod create_app_xnft;
+mod create_category;
 mod create_collectible_xnft;
+mod create_curator;
+mod create_delegate;
 mod create_install;
 mod create_permissioned_install;
 mod create_review;
+mod create_review_reply;
+mod create_voucher_install;
+mod delete_curator;
+mod delete_delegate;
 mod delete_install;
 mod delete_review;
+mod delete_review_reply;
+mod delete_update_proposal;
 mod delete_xnft;
 mod donate;
 mod grant_access;
+mod grant_access_batch;
+mod initialize_protocol_config;
+mod migrate_xnft;
+mod moderate_xnft;
+mod propose_protocol_admin;
+mod propose_xnft_update;
+mod refund_install;
+mod remove_review;
+mod renew_install;
 mod revoke_access;
+mod revoke_access_batch;
+mod revoke_install;
+mod rollback_xnft;
+mod set_allowlist;
 mod set_curator;
 mod set_curator_verification;
+mod set_install_gate;
+mod set_protocol_paused;
+mod set_review_hidden;
 mod set_suspended;
+mod set_update_proposal_approval;
+mod settle_install;
 mod transfer;
+mod update_category;
+mod update_curator;
+mod update_delegate;
+mod update_protocol_config;
+mod update_review;
+mod update_review_reply;
 mod update_xnft;
+mod verify_install;
 
+pub use accept_protocol_admin::*;
+pub use apply_update_proposal::*;
+pub use create_allowlisted_install::*;
 pub use create_app_xnft::*;
+pub use create_category::*;
 pub use create_collectible_xnft::*;
+pub use create_curator::*;
+pub use create_delegate::*;
 pub use create_install::*;
 pub use create_permissioned_install::*;
 pub use create_review::*;
+pub use create_review_reply::*;
+pub use create_voucher_install::*;
+pub use delete_curator::*;
+pub use delete_delegate::*;
 pub use delete_install::*;
 pub use delete_review::*;
+pub use delete_review_reply::*;
+pub use delete_update_proposal::*;
 pub use delete_xnft::*;
 pub use donate::*;
 pub use grant_access::*;
+pub use grant_access_batch::*;
+pub use initialize_protocol_config::*;
+pub use migrate_xnft::*;
+pub use moderate_xnft::*;
+pub use propose_protocol_admin::*;
+pub use propose_xnft_update::*;
+pub use refund_install::*;
+pub use remove_review::*;
+pub use renew_install::*;
 pub use revoke_access::*;
+pub use revoke_access_batch::*;
+pub use revoke_install::*;
+pub use rollback_xnft::*;
+pub use set_allowlist::*;
 pub use set_curator::*;
 pub use set_curator_verification::*;
+pub use set_install_gate::*;
+pub use set_protocol_paused::*;
+pub use set_review_hidden::*;
 pub use set_suspended::*;
+pub use set_update_proposal_approval::*;
+pub use settle_install::*;
 pub use transfer::*;
+pub use update_category::*;
+pub use update_curator::*;
+pub use update_delegate::*;
+pub use update_protocol_config::*;
+pub use update_review::*;
+pub use update_review_reply::*;
 pub use update_xnft::*;
+pub use verify_install::*;
@@ -0,0 +1,65 @@
+// Copyright (C) 2023 Blue Coral, Inc.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use anchor_lang::prelude::*;
+
+use super::set_suspension;
+use crate::state::{Curator, ProtocolConfig, SuspensionRole, Xnft};
+use crate::CustomError;
+
+#[derive(Accounts)]
+pub struct ModerateXnft<'info> {
+    #[account(mut)]
+    pub xnft: Account<'info, Xnft>,
+
+    pub curator: Option<Account<'info, Curator>>,
+
+    #[account(
+        seeds = ["config".as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Option<Account<'info, ProtocolConfig>>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn moderate_xnft_handler(ctx: Context<ModerateXnft>, flag: bool, reason: u8) -> Result<()> {
+    let xnft = &ctx.accounts.xnft;
+    let authority = ctx.accounts.authority.key;
+
+    // The signer acts either as an authority of the verified curator of the
+    // xNFT or as the protocol admin, depending on which account is provided.
+    let role = match (&ctx.accounts.curator, &ctx.accounts.config) {
+        (Some(curator), None) => {
+            let verified = xnft
+                .curator
+                .as_ref()
+                .is_some_and(|c| c.verified && c.pubkey == curator.key());
+
+            require!(
+                verified && curator.is_authorized(authority),
+                CustomError::ModeratorMismatch,
+            );
+            SuspensionRole::Curator
+        }
+        (None, Some(config)) => {
+            require_keys_eq!(config.admin, *authority, CustomError::ModeratorMismatch);
+            SuspensionRole::Admin
+        }
+        _ => return Err(error!(CustomError::ModeratorMismatch)),
+    };
+
+    set_suspension(&mut ctx.accounts.xnft, *authority, role, flag, reason)
+}
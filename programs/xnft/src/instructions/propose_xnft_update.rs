@@ -0,0 +1,135 @@
+// Copyright (C) 2023 Blue Coral, Inc.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use anchor_lang::prelude::*;
+use anchor_spl::metadata::MetadataAccount;
+use anchor_spl::token::TokenAccount;
+use mpl_token_metadata::state::{MAX_NAME_LENGTH, MAX_URI_LENGTH};
+
+use super::verify_updater;
+use crate::events::UpdateProposalCreated;
+use crate::state::{CuratorStatus, Delegate, ProtocolConfig, UpdateParams, UpdateProposal, Xnft};
+use crate::CustomError;
+
+#[derive(Accounts)]
+pub struct ProposeXnftUpdate<'info> {
+    #[account(has_one = master_metadata)]
+    pub xnft: Account<'info, Xnft>,
+
+    #[account(
+        constraint = master_token.mint == xnft.master_mint,
+        constraint = master_token.amount == 1,
+    )]
+    pub master_token: Account<'info, TokenAccount>,
+
+    #[account(
+        constraint = master_metadata.is_mutable @ CustomError::MetadataIsImmutable,
+    )]
+    pub master_metadata: Account<'info, MetadataAccount>,
+
+    #[account(
+        seeds = [
+            "delegate".as_bytes(),
+            xnft.key().as_ref(),
+            proposer.key().as_ref(),
+        ],
+        bump = delegate.bump,
+    )]
+    pub delegate: Option<Account<'info, Delegate>>,
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Auto derived below.
+    ////////////////////////////////////////////////////////////////////////////
+    #[account(
+        init,
+        payer = proposer,
+        space = UpdateProposal::LEN,
+        seeds = [
+            "proposal".as_bytes(),
+            xnft.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub proposal: Account<'info, UpdateProposal>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    #[account(
+        seeds = ["config".as_bytes()],
+        bump = config.bump,
+        constraint = !config.paused @ CustomError::ProtocolPaused,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn propose_xnft_update_handler(
+    ctx: Context<ProposeXnftUpdate>,
+    updates: UpdateParams,
+) -> Result<()> {
+    verify_updater(
+        &ctx.accounts.xnft,
+        &ctx.accounts.master_token,
+        &ctx.accounts.master_metadata,
+        ctx.accounts.delegate.as_ref(),
+        ctx.accounts.proposer.key,
+        updates.required_permissions(&ctx.accounts.xnft),
+    )?;
+
+    // Proposals are only reviewed by a verified curator, otherwise the owner
+    // can apply the updates directly through `update_xnft`.
+    let curator = match ctx.accounts.xnft.curator {
+        Some(CuratorStatus {
+            pubkey,
+            verified: true,
+        }) => pubkey,
+        _ => return Err(error!(CustomError::CuratorNotVerified)),
+    };
+
+    // The proposal account is allocated for the maximum lengths of the
+    // name and URI, so they must be checked before being stored.
+    if let Some(name) = &updates.name {
+        require!(
+            name.len() <= MAX_NAME_LENGTH,
+            CustomError::NameExceedsMaxLength,
+        );
+    }
+
+    if let Some(uri) = &updates.uri {
+        require!(
+            uri.len() <= MAX_URI_LENGTH,
+            CustomError::UriExceedsMaxLength,
+        );
+    }
+
+    let proposal = &mut ctx.accounts.proposal;
+    **proposal = UpdateProposal::try_new(
+        ctx.accounts.xnft.key(),
+        *ctx.accounts.proposer.key,
+        curator,
+        updates,
+        *ctx.bumps.get("proposal").unwrap(),
+    )?;
+
+    emit!(UpdateProposalCreated {
+        curator,
+        proposer: ctx.accounts.proposer.key(),
+        xnft: ctx.accounts.xnft.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,100 @@
+// Copyright (C) 2023 Blue Coral, Inc.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::events::InstallationRefunded;
+use crate::payments::Payment;
+use crate::state::{Escrow, Install};
+use crate::CustomError;
+
+#[derive(Accounts)]
+pub struct RefundInstall<'info> {
+    #[account(
+        mut,
+        close = authority,
+        has_one = authority,
+    )]
+    pub install: Account<'info, Install>,
+
+    #[account(
+        mut,
+        close = payer,
+        has_one = install,
+        has_one = payer,
+        seeds = [
+            "escrow".as_bytes(),
+            install.key().as_ref(),
+            &install.edition.to_le_bytes(),
+        ],
+        bump = escrow.bump,
+    )]
+    pub escrow: Account<'info, Escrow>,
+
+    #[account(mut)]
+    pub escrow_token: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub install_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: escrow has_one constraint.
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer_token: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+impl<'info> RefundInstall<'info> {
+    pub fn payment(&self) -> Result<Payment<'info>> {
+        Payment::try_new_escrow(
+            &self.escrow,
+            self.escrow_token.as_ref(),
+            self.install_mint.as_ref(),
+            self.token_program.as_ref(),
+        )
+    }
+}
+
+pub fn refund_install_handler(ctx: Context<RefundInstall>) -> Result<()> {
+    let escrow = &ctx.accounts.escrow;
+
+    require!(
+        escrow.is_refundable(Clock::get()?.unix_timestamp),
+        CustomError::RefundWindowClosed,
+    );
+
+    // Return the held payment to the wallet that paid for the installation,
+    // with the rent of the escrow and the installation closed by the constraints.
+    let payment = ctx.accounts.payment()?;
+    let payer = payment.destination(
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.payer_token.as_ref(),
+    )?;
+    payment.transfer(payer, escrow.amount)?;
+    payment.close(ctx.accounts.payer.to_account_info())?;
+
+    emit!(InstallationRefunded {
+        amount: escrow.amount,
+        installer: ctx.accounts.install.authority,
+        xnft: escrow.xnft,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,81 @@
+// Copyright (C) 2023 Blue Coral, Inc.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use anchor_lang::prelude::*;
+
+use crate::events::ReviewRemoved;
+use crate::state::{Curator, Review, ReviewReply, Xnft};
+use crate::CustomError;
+
+#[derive(Accounts)]
+pub struct RemoveReview<'info> {
+    #[account(
+        mut,
+        close = author,
+        has_one = author,
+        has_one = xnft,
+    )]
+    pub review: Account<'info, Review>,
+
+    #[account(mut, has_one = review)]
+    pub reply: Option<Account<'info, ReviewReply>>,
+
+    /// CHECK: is validated against the author of the `reply` to refund its rent.
+    #[account(mut)]
+    pub reply_author: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        mut,
+        constraint = xnft.curator.as_ref().map(|c| c.pubkey) == Some(curator.key()) @ CustomError::CuratorMismatch,
+        constraint = xnft.curator.as_ref().map_or(false, |c| c.verified) @ CustomError::CuratorNotVerified,
+    )]
+    pub xnft: Account<'info, Xnft>,
+
+    /// CHECK: is validated by the `has_one` constraint on `review` to refund the rent.
+    #[account(mut)]
+    pub author: UncheckedAccount<'info>,
+
+    pub curator: Account<'info, Curator>,
+
+    #[account(
+        constraint = curator.is_authorized(authority.key) @ CustomError::CuratorAuthorityMismatch,
+    )]
+    pub authority: Signer<'info>,
+}
+
+pub fn remove_review_handler(ctx: Context<RemoveReview>, reason: u8) -> Result<()> {
+    let xnft = &mut ctx.accounts.xnft;
+    let review = &ctx.accounts.review;
+
+    // A hidden review was already taken out of the xNFT metrics.
+    if !review.hidden {
+        xnft.remove_rating(review.rating);
+    }
+
+    ReviewReply::close_with_review(
+        ctx.accounts.reply.as_ref(),
+        ctx.accounts.reply_author.as_ref(),
+    )?;
+
+    emit!(ReviewRemoved {
+        author: review.author,
+        curator: ctx.accounts.curator.key(),
+        reason,
+        review: review.key(),
+        xnft: xnft.key(),
+    });
+
+    Ok(())
+}
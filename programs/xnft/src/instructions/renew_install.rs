@@ -0,0 +1,133 @@
+// Copyright (C) 2023 Blue Coral, Inc.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+use anchor_lang::prelude::*;
+use anchor_spl::metadata::MetadataAccount;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::events::InstallationRenewed;
+use crate::payments::{InstallPayees, Payment};
+use crate::state::{Install, Kind, ProtocolConfig, Xnft};
+use crate::CustomError;
+
+#[derive(Accounts)]
+pub struct RenewInstall<'info> {
+    #[account(
+        has_one = install_vault,
+        constraint = xnft.kind == Kind::App @ CustomError::MustBeApp,
+        constraint = !xnft.suspended @ CustomError::SuspendedInstallation,
+    )]
+    pub xnft: Account<'info, Xnft>,
+
+    /// CHECK: xnft has_one constraint.
+    #[account(mut)]
+    pub install_vault: UncheckedAccount<'info>,
+
+    #[account(address = xnft.master_metadata)]
+    pub master_metadata: Option<Box<Account<'info, MetadataAccount>>>,
+
+    pub install_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub install_vault_token: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub authority_token: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: config address constraint.
+    #[account(mut, address = config.treasury)]
+    pub treasury: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub treasury_token: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        has_one = xnft,
+        constraint = install.expires_ts.is_some() @ CustomError::NotSubscription,
+    )]
+    pub install: Account<'info, Install>,
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Auto derived below.
+    ////////////////////////////////////////////////////////////////////////////
+    #[account(
+        seeds = ["config".as_bytes()],
+        bump = config.bump,
+        constraint = !config.paused @ CustomError::ProtocolPaused,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+impl<'info> RenewInstall<'info> {
+    pub fn payment(&self) -> Result<Payment<'info>> {
+        Payment::try_new(
+            self.xnft.install_mint,
+            self.authority.to_account_info(),
+            self.authority_token.as_ref(),
+            self.install_mint.as_ref(),
+            self.system_program.to_account_info(),
+            self.token_program.as_ref(),
+        )
+    }
+
+    pub fn payees<'a>(&'a self, creators: &'a [AccountInfo<'info>]) -> InstallPayees<'a, 'info> {
+        InstallPayees {
+            creators,
+            fee_basis_points: self.config.fee_basis_points,
+            install_vault: self.install_vault.to_account_info(),
+            install_vault_token: self.install_vault_token.as_ref(),
+            master_metadata: self.master_metadata.as_deref(),
+            split: self.xnft.split_install_payments,
+            treasury: self.treasury.to_account_info(),
+            treasury_token: self.treasury_token.as_ref(),
+        }
+    }
+}
+
+pub fn renew_install_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, RenewInstall<'info>>,
+) -> Result<()> {
+    let xnft = &ctx.accounts.xnft;
+    let period = xnft
+        .subscription_period
+        .ok_or(error!(CustomError::NotSubscription))?;
+
+    // Pay for another subscription period of the xNFT, if needed, in the
+    // same manner as the payment for the initial installation.
+    if xnft.install_price > 0 {
+        ctx.accounts.payment()?.pay_install(
+            ctx.accounts.payees(ctx.remaining_accounts),
+            xnft.install_price,
+        )?;
+    }
+
+    // Extend the expiry of the installation by the period.
+    let install = &mut ctx.accounts.install;
+    install.renew(period, Clock::get()?.unix_timestamp)?;
+
+    emit!(InstallationRenewed {
+        expires_ts: install.expires_ts.unwrap(),
+        installer: install.authority,
+        xnft: install.xnft,
+    });
+
+    Ok(())
+}
@@ -14,18 +14,35 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
 
-use crate::state::{Access, Kind, Xnft};
-use crate::CustomError;
+use super::verify_owner_or_delegate;
+use crate::state::{Access, Delegate, Kind, Xnft};
+use crate::{CustomError, PERMISSION_MANAGE_ACCESS};
 
 #[derive(Accounts)]
 pub struct RevokeAccess<'info> {
     #[account(
-        constraint = xnft.install_authority == Some(*authority.key),
         constraint = xnft.kind == Kind::App @ CustomError::MustBeApp,
     )]
     pub xnft: Account<'info, Xnft>,
 
+    #[account(
+        seeds = [
+            "delegate".as_bytes(),
+            xnft.key().as_ref(),
+            authority.key().as_ref(),
+        ],
+        bump = delegate.bump,
+    )]
+    pub delegate: Option<Account<'info, Delegate>>,
+
+    #[account(
+        constraint = master_token.mint == xnft.master_mint,
+        constraint = master_token.amount == 1,
+    )]
+    pub master_token: Option<Account<'info, TokenAccount>>,
+
     /// CHECK: validated with has_one and seeding on `access`.
     pub wallet: UncheckedAccount<'info>,
 
@@ -50,6 +67,21 @@ pub struct RevokeAccess<'info> {
     pub authority: Signer<'info>,
 }
 
-pub fn revoke_access_handler(_ctx: Context<RevokeAccess>) -> Result<()> {
+pub fn revoke_access_handler(ctx: Context<RevokeAccess>) -> Result<()> {
+    let install_authority = ctx
+        .accounts
+        .xnft
+        .install_authority
+        .ok_or(error!(CustomError::InstallAuthorityMismatch))?;
+
+    verify_owner_or_delegate(
+        &install_authority,
+        ctx.accounts.authority.key,
+        ctx.accounts.delegate.as_ref(),
+        ctx.accounts.master_token.as_ref().map(|t| &t.owner),
+        PERMISSION_MANAGE_ACCESS,
+        CustomError::InstallAuthorityMismatch,
+    )?;
+
     Ok(())
 }
@@ -0,0 +1,81 @@
+// Copyright (C) 2023 Blue Coral, Inc.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use super::verify_owner_or_delegate;
+use crate::state::{Access, Delegate, Kind, Xnft};
+use crate::{CustomError, PERMISSION_MANAGE_ACCESS};
+
+#[derive(Accounts)]
+pub struct RevokeAccessBatch<'info> {
+    #[account(
+        constraint = xnft.kind == Kind::App @ CustomError::MustBeApp,
+    )]
+    pub xnft: Account<'info, Xnft>,
+
+    #[account(
+        seeds = [
+            "delegate".as_bytes(),
+            xnft.key().as_ref(),
+            authority.key().as_ref(),
+        ],
+        bump = delegate.bump,
+    )]
+    pub delegate: Option<Account<'info, Delegate>>,
+
+    #[account(
+        constraint = master_token.mint == xnft.master_mint,
+        constraint = master_token.amount == 1,
+    )]
+    pub master_token: Option<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+pub fn revoke_access_batch_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, RevokeAccessBatch<'info>>,
+) -> Result<()> {
+    let install_authority = ctx
+        .accounts
+        .xnft
+        .install_authority
+        .ok_or(error!(CustomError::InstallAuthorityMismatch))?;
+
+    verify_owner_or_delegate(
+        &install_authority,
+        ctx.accounts.authority.key,
+        ctx.accounts.delegate.as_ref(),
+        ctx.accounts.master_token.as_ref().map(|t| &t.owner),
+        PERMISSION_MANAGE_ACCESS,
+        CustomError::InstallAuthorityMismatch,
+    )?;
+
+    // Each remaining account must be an access account of the xNFT, which is
+    // closed to the authority as it would be by `revoke_access`.
+    for info in ctx.remaining_accounts {
+        let access = Account::<Access>::try_from(info)?;
+        require_keys_eq!(
+            access.xnft,
+            ctx.accounts.xnft.key(),
+            CustomError::AccessAccountMismatch,
+        );
+        access.close(ctx.accounts.authority.to_account_info())?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,87 @@
+// Copyright (C) 2023 Blue Coral, Inc.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::events::InstallationRevoked;
+use crate::state::{Install, Xnft};
+use crate::CustomError;
+
+#[derive(Accounts)]
+pub struct RevokeInstall<'info> {
+    pub xnft: Account<'info, Xnft>,
+
+    #[account(
+        constraint = master_token.mint == xnft.master_mint,
+        constraint = master_token.amount == 1,
+    )]
+    pub master_token: Option<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        close = installer,
+        has_one = xnft,
+    )]
+    pub install: Account<'info, Install>,
+
+    /// CHECK: the address is validated by the seeds and must not be initialized.
+    #[account(
+        seeds = [
+            "escrow".as_bytes(),
+            install.key().as_ref(),
+            &install.edition.to_le_bytes(),
+        ],
+        bump,
+        constraint = escrow.data_is_empty() @ CustomError::EscrowNotSettled,
+    )]
+    pub escrow: UncheckedAccount<'info>,
+
+    /// CHECK: address constraint to the authority of the install.
+    #[account(
+        mut,
+        address = install.authority,
+    )]
+    pub installer: UncheckedAccount<'info>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn revoke_install_handler(ctx: Context<RevokeInstall>) -> Result<()> {
+    let xnft = &ctx.accounts.xnft;
+    let authority = ctx.accounts.authority.key;
+
+    // Installations can be revoked by the install authority of a private xNFT
+    // or the owner of its master token, but not while the payment is still
+    // refundable so that the installer can't be denied their refund.
+    let is_owner = ctx
+        .accounts
+        .master_token
+        .as_ref()
+        .is_some_and(|t| t.owner == *authority);
+
+    require!(
+        xnft.install_authority == Some(*authority) || is_owner,
+        CustomError::InstallAuthorityMismatch,
+    );
+
+    emit!(InstallationRevoked {
+        authority: *authority,
+        installer: ctx.accounts.installer.key(),
+        xnft: xnft.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,119 @@
+// Copyright (C) 2023 Blue Coral, Inc.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use anchor_lang::prelude::*;
+use anchor_spl::metadata::{Metadata, MetadataAccount};
+use anchor_spl::token::TokenAccount;
+
+use super::{set_uri, verify_curation, verify_updater};
+use crate::events::XnftRolledBack;
+use crate::state::{Curator, Delegate, ProtocolConfig, Release, Xnft};
+use crate::{CustomError, PERMISSION_UPDATE};
+
+#[derive(Accounts)]
+pub struct RollbackXnft<'info> {
+    #[account(
+        mut,
+        has_one = master_metadata,
+    )]
+    pub xnft: Account<'info, Xnft>,
+
+    #[account(
+        constraint = master_token.mint == xnft.master_mint,
+        constraint = master_token.amount == 1,
+    )]
+    pub master_token: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = master_metadata.is_mutable @ CustomError::MetadataIsImmutable,
+    )]
+    pub master_metadata: Account<'info, MetadataAccount>,
+
+    #[account(
+        has_one = xnft,
+        constraint = release.version != xnft.current_release @ CustomError::ReleaseIsCurrent,
+    )]
+    pub release: Account<'info, Release>,
+
+    #[account(
+        seeds = [
+            "delegate".as_bytes(),
+            xnft.key().as_ref(),
+            updater.key().as_ref(),
+        ],
+        bump = delegate.bump,
+    )]
+    pub delegate: Option<Account<'info, Delegate>>,
+
+    pub curator: Option<Account<'info, Curator>>,
+
+    /// CHECK: is validated against the `curator` account in the handler if the xNFT has a verified curator.
+    pub curation_authority: UncheckedAccount<'info>,
+    pub updater: Signer<'info>,
+
+    #[account(
+        seeds = ["config".as_bytes()],
+        bump = config.bump,
+        constraint = !config.paused @ CustomError::ProtocolPaused,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    pub metadata_program: Program<'info, Metadata>,
+}
+
+pub fn rollback_xnft_handler(ctx: Context<RollbackXnft>) -> Result<()> {
+    verify_updater(
+        &ctx.accounts.xnft,
+        &ctx.accounts.master_token,
+        &ctx.accounts.master_metadata,
+        ctx.accounts.delegate.as_ref(),
+        ctx.accounts.updater.key,
+        PERMISSION_UPDATE,
+    )?;
+
+    verify_curation(
+        &ctx.accounts.xnft,
+        ctx.accounts.curator.as_ref(),
+        &ctx.accounts.curation_authority,
+    )?;
+
+    // Point the xNFT back at the recorded release without publishing a new
+    // one, so the release history stays append-only.
+    let release = &ctx.accounts.release;
+    let xnft = &mut ctx.accounts.xnft;
+    let previous_release = xnft.current_release;
+
+    set_uri(
+        xnft,
+        &ctx.accounts.master_metadata,
+        &ctx.accounts.metadata_program,
+        None,
+        release.uri.clone(),
+    )?;
+
+    xnft.content_hash = release.content_hash;
+    xnft.current_release = release.version;
+    xnft.updated_ts = Clock::get()?.unix_timestamp;
+
+    emit!(XnftRolledBack {
+        content_hash: xnft.content_hash,
+        previous_release,
+        release: release.version,
+        xnft: xnft.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,84 @@
+// Copyright (C) 2023 Blue Coral, Inc.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use super::verify_owner_or_delegate;
+use crate::events::AllowlistUpdated;
+use crate::state::{Delegate, Kind, ProtocolConfig, Xnft};
+use crate::{CustomError, PERMISSION_MANAGE_ACCESS};
+
+#[derive(Accounts)]
+pub struct SetAllowlist<'info> {
+    #[account(
+        mut,
+        constraint = xnft.kind == Kind::App @ CustomError::MustBeApp,
+    )]
+    pub xnft: Account<'info, Xnft>,
+
+    #[account(
+        seeds = [
+            "delegate".as_bytes(),
+            xnft.key().as_ref(),
+            authority.key().as_ref(),
+        ],
+        bump = delegate.bump,
+    )]
+    pub delegate: Option<Account<'info, Delegate>>,
+
+    #[account(
+        constraint = master_token.mint == xnft.master_mint,
+        constraint = master_token.amount == 1,
+    )]
+    pub master_token: Option<Account<'info, TokenAccount>>,
+
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = ["config".as_bytes()],
+        bump = config.bump,
+        constraint = !config.paused @ CustomError::ProtocolPaused,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+}
+
+pub fn set_allowlist_handler(ctx: Context<SetAllowlist>, root: Option<[u8; 32]>) -> Result<()> {
+    let install_authority = ctx
+        .accounts
+        .xnft
+        .install_authority
+        .ok_or(error!(CustomError::InstallAuthorityMismatch))?;
+
+    verify_owner_or_delegate(
+        &install_authority,
+        ctx.accounts.authority.key,
+        ctx.accounts.delegate.as_ref(),
+        ctx.accounts.master_token.as_ref().map(|t| &t.owner),
+        PERMISSION_MANAGE_ACCESS,
+        CustomError::InstallAuthorityMismatch,
+    )?;
+
+    let xnft = &mut ctx.accounts.xnft;
+    xnft.allowlist_root = root;
+    xnft.updated_ts = Clock::get()?.unix_timestamp;
+
+    emit!(AllowlistUpdated {
+        root,
+        xnft: xnft.key(),
+    });
+
+    Ok(())
+}
@@ -16,8 +16,9 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::TokenAccount;
 
-use crate::state::{CuratorStatus, Xnft};
-use crate::CustomError;
+use super::verify_owner_or_delegate;
+use crate::state::{Curator, CuratorStatus, Delegate, ProtocolConfig, Xnft};
+use crate::{CustomError, PERMISSION_UPDATE};
 
 #[derive(Accounts)]
 pub struct SetCurator<'info> {
@@ -25,29 +26,70 @@ pub struct SetCurator<'info> {
     pub xnft: Account<'info, Xnft>,
 
     #[account(
-        associated_token::mint = xnft.master_mint,
-        associated_token::authority = authority,
+        constraint = master_token.mint == xnft.master_mint,
         constraint = master_token.amount == 1,
     )]
     pub master_token: Account<'info, TokenAccount>,
 
-    /// CHECK: allow any account to be assigned as a curator.
-    pub curator: UncheckedAccount<'info>,
+    #[account(
+        seeds = [
+            "delegate".as_bytes(),
+            xnft.key().as_ref(),
+            authority.key().as_ref(),
+        ],
+        bump = delegate.bump,
+    )]
+    pub delegate: Option<Account<'info, Delegate>>,
+
+    #[account(mut)]
+    pub curator: Account<'info, Curator>,
+
+    /// CHECK: is validated in the handler against the curator being replaced,
+    /// which is not a `Curator` account if it is a wallet assigned before them.
+    #[account(mut)]
+    pub previous_curator: Option<UncheckedAccount<'info>>,
 
     pub authority: Signer<'info>,
+
+    #[account(
+        seeds = ["config".as_bytes()],
+        bump = config.bump,
+        constraint = !config.paused @ CustomError::ProtocolPaused,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
 }
 
 pub fn set_curator_handler(ctx: Context<SetCurator>) -> Result<()> {
+    verify_owner_or_delegate(
+        &ctx.accounts.master_token.owner,
+        ctx.accounts.authority.key,
+        ctx.accounts.delegate.as_ref(),
+        Some(&ctx.accounts.master_token.owner),
+        PERMISSION_UPDATE,
+        CustomError::UpdateAuthorityMismatch,
+    )?;
+
     let xnft = &mut ctx.accounts.xnft;
+    let curator = &mut ctx.accounts.curator;
 
-    if let Some(CuratorStatus { verified: true, .. }) = xnft.curator {
-        return Err(error!(CustomError::CuratorAlreadySet));
+    match xnft.curator {
+        Some(CuratorStatus { verified: true, .. }) => {
+            return Err(error!(CustomError::CuratorAlreadySet));
+        }
+        Some(CuratorStatus { pubkey, .. }) if pubkey == curator.key() => return Ok(()),
+        Some(CuratorStatus { pubkey, .. }) => {
+            // The unverified curator being replaced no longer counts the xNFT.
+            let previous = ctx.accounts.previous_curator.as_deref();
+            Curator::unassign(Curator::verify_assigned(&pubkey, previous)?)?;
+        }
+        None => {}
     }
 
     xnft.curator = Some(CuratorStatus {
-        pubkey: *ctx.accounts.curator.key,
+        pubkey: curator.key(),
         verified: false,
     });
+    curator.total_xnfts += 1;
 
     Ok(())
 }
@@ -15,28 +15,50 @@
 
 use anchor_lang::prelude::*;
 
-use crate::state::Xnft;
+use crate::state::{Curator, ProtocolConfig, Xnft};
 use crate::CustomError;
 
 #[derive(Accounts)]
 pub struct SetCuratorVerification<'info> {
-    #[account(
-        mut,
-        constraint = xnft.curator.as_ref().map(|c| c.pubkey) == Some(*curator.key) @ CustomError::CuratorMismatch,
-    )]
+    #[account(mut)]
     pub xnft: Account<'info, Xnft>,
 
-    pub curator: Signer<'info>,
+    /// Omitted if the assigned curator is a wallet from before `Curator` accounts.
+    pub curator: Option<Account<'info, Curator>>,
+
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = ["config".as_bytes()],
+        bump = config.bump,
+        constraint = !config.paused @ CustomError::ProtocolPaused,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
 }
 
 pub fn set_curator_verification_handler(
     ctx: Context<SetCuratorVerification>,
     value: bool,
 ) -> Result<()> {
-    if let Some(curator) = &mut ctx.accounts.xnft.curator {
-        curator.verified = value;
-    } else {
-        return Err(error!(CustomError::CuratorMismatch));
-    }
+    let authority = ctx.accounts.authority.key;
+    let status = ctx
+        .accounts
+        .xnft
+        .curator
+        .as_mut()
+        .ok_or(error!(CustomError::CuratorMismatch))?;
+
+    // A wallet assigned as the curator before `Curator` accounts existed signs
+    // for itself, which lets it unverify to be replaced by a `Curator` account.
+    let authorized = match &ctx.accounts.curator {
+        Some(curator) => {
+            require_keys_eq!(status.pubkey, curator.key(), CustomError::CuratorMismatch);
+            curator.is_authorized(authority)
+        }
+        None => status.pubkey == *authority,
+    };
+    require!(authorized, CustomError::CuratorAuthorityMismatch);
+
+    status.verified = value;
     Ok(())
 }
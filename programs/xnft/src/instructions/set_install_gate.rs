@@ -0,0 +1,85 @@
+// Copyright (C) 2023 Blue Coral, Inc.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use super::verify_owner_or_delegate;
+use crate::events::InstallGateUpdated;
+use crate::state::{Delegate, InstallGate, Kind, ProtocolConfig, Xnft};
+use crate::{CustomError, PERMISSION_MANAGE_ACCESS};
+
+#[derive(Accounts)]
+pub struct SetInstallGate<'info> {
+    #[account(
+        mut,
+        constraint = xnft.kind == Kind::App @ CustomError::MustBeApp,
+    )]
+    pub xnft: Account<'info, Xnft>,
+
+    #[account(
+        constraint = master_token.mint == xnft.master_mint,
+        constraint = master_token.amount == 1,
+    )]
+    pub master_token: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [
+            "delegate".as_bytes(),
+            xnft.key().as_ref(),
+            authority.key().as_ref(),
+        ],
+        bump = delegate.bump,
+    )]
+    pub delegate: Option<Account<'info, Delegate>>,
+
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = ["config".as_bytes()],
+        bump = config.bump,
+        constraint = !config.paused @ CustomError::ProtocolPaused,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+}
+
+pub fn set_install_gate_handler(
+    ctx: Context<SetInstallGate>,
+    gate: Option<InstallGate>,
+) -> Result<()> {
+    verify_owner_or_delegate(
+        &ctx.accounts.master_token.owner,
+        ctx.accounts.authority.key,
+        ctx.accounts.delegate.as_ref(),
+        Some(&ctx.accounts.master_token.owner),
+        PERMISSION_MANAGE_ACCESS,
+        CustomError::UpdateAuthorityMismatch,
+    )?;
+
+    if let Some(InstallGate::Token { amount, .. }) = gate {
+        require_gt!(amount, 0, CustomError::InvalidInstallGate);
+    }
+
+    let xnft = &mut ctx.accounts.xnft;
+    xnft.install_gate = gate.clone();
+    xnft.updated_ts = Clock::get()?.unix_timestamp;
+
+    emit!(InstallGateUpdated {
+        gate,
+        xnft: xnft.key(),
+    });
+
+    Ok(())
+}
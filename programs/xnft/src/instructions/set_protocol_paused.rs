@@ -0,0 +1,44 @@
+// Copyright (C) 2023 Blue Coral, Inc.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use anchor_lang::prelude::*;
+
+use crate::events::ProtocolPauseUpdated;
+use crate::state::ProtocolConfig;
+use crate::CustomError;
+
+#[derive(Accounts)]
+pub struct SetProtocolPaused<'info> {
+    #[account(
+        mut,
+        seeds = ["config".as_bytes()],
+        bump = config.bump,
+        has_one = admin @ CustomError::ProtocolAdminMismatch,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+pub fn set_protocol_paused_handler(ctx: Context<SetProtocolPaused>, paused: bool) -> Result<()> {
+    ctx.accounts.config.paused = paused;
+
+    emit!(ProtocolPauseUpdated {
+        admin: *ctx.accounts.admin.key,
+        paused,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,73 @@
+// Copyright (C) 2023 Blue Coral, Inc.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use anchor_lang::prelude::*;
+
+use crate::events::ReviewHidden;
+use crate::state::{Curator, Review, Xnft};
+use crate::CustomError;
+
+#[derive(Accounts)]
+pub struct SetReviewHidden<'info> {
+    #[account(
+        mut,
+        has_one = xnft,
+    )]
+    pub review: Account<'info, Review>,
+
+    #[account(
+        mut,
+        constraint = xnft.curator.as_ref().map(|c| c.pubkey) == Some(curator.key()) @ CustomError::CuratorMismatch,
+        constraint = xnft.curator.as_ref().map_or(false, |c| c.verified) @ CustomError::CuratorNotVerified,
+    )]
+    pub xnft: Account<'info, Xnft>,
+
+    pub curator: Account<'info, Curator>,
+
+    #[account(
+        constraint = curator.is_authorized(authority.key) @ CustomError::CuratorAuthorityMismatch,
+    )]
+    pub authority: Signer<'info>,
+}
+
+pub fn set_review_hidden_handler(
+    ctx: Context<SetReviewHidden>,
+    value: bool,
+    reason: u8,
+) -> Result<()> {
+    let xnft = &mut ctx.accounts.xnft;
+    let review = &mut ctx.accounts.review;
+
+    // Hidden reviews do not count towards the cumulative rating of the
+    // xNFT, so only adjust the metrics when the flag actually changes.
+    if review.hidden != value {
+        if value {
+            xnft.remove_rating(review.rating);
+        } else {
+            xnft.add_rating(review.rating);
+        }
+        review.hidden = value;
+    }
+
+    emit!(ReviewHidden {
+        curator: ctx.accounts.curator.key(),
+        hidden: value,
+        reason,
+        review: review.key(),
+        xnft: xnft.key(),
+    });
+
+    Ok(())
+}
@@ -16,7 +16,10 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::TokenAccount;
 
-use crate::state::Xnft;
+use super::verify_owner_or_delegate;
+use crate::events::{XnftSuspended, XnftUnsuspended};
+use crate::state::{Delegate, SuspensionRole, Xnft};
+use crate::{CustomError, PERMISSION_SUSPEND};
 
 #[derive(Accounts)]
 pub struct SetSuspended<'info> {
@@ -24,17 +27,70 @@ pub struct SetSuspended<'info> {
     pub xnft: Account<'info, Xnft>,
 
     #[account(
-        associated_token::mint = xnft.master_mint,
-        associated_token::authority = authority,
+        constraint = master_token.mint == xnft.master_mint,
         constraint = master_token.amount == 1,
     )]
     pub master_token: Account<'info, TokenAccount>,
 
+    #[account(
+        seeds = [
+            "delegate".as_bytes(),
+            xnft.key().as_ref(),
+            authority.key().as_ref(),
+        ],
+        bump = delegate.bump,
+    )]
+    pub delegate: Option<Account<'info, Delegate>>,
+
     pub authority: Signer<'info>,
 }
 
-pub fn set_suspended_handler(ctx: Context<SetSuspended>, flag: bool) -> Result<()> {
-    let xnft = &mut ctx.accounts.xnft;
-    xnft.suspended = flag;
+pub fn set_suspended_handler(ctx: Context<SetSuspended>, flag: bool, reason: u8) -> Result<()> {
+    verify_owner_or_delegate(
+        &ctx.accounts.master_token.owner,
+        ctx.accounts.authority.key,
+        ctx.accounts.delegate.as_ref(),
+        Some(&ctx.accounts.master_token.owner),
+        PERMISSION_SUSPEND,
+        CustomError::UpdateAuthorityMismatch,
+    )?;
+
+    set_suspension(
+        &mut ctx.accounts.xnft,
+        ctx.accounts.authority.key(),
+        SuspensionRole::Owner,
+        flag,
+        reason,
+    )
+}
+
+/// Suspends or lifts the suspension of the xNFT on behalf of the authority in
+/// the role and emits the respective event.
+pub(crate) fn set_suspension(
+    xnft: &mut Account<'_, Xnft>,
+    authority: Pubkey,
+    role: SuspensionRole,
+    flag: bool,
+    reason: u8,
+) -> Result<()> {
+    if flag {
+        xnft.suspend(authority, role, reason, Clock::get()?.unix_timestamp)?;
+
+        emit!(XnftSuspended {
+            authority,
+            reason,
+            role,
+            xnft: xnft.key(),
+        });
+    } else {
+        xnft.unsuspend(role)?;
+
+        emit!(XnftUnsuspended {
+            authority,
+            role,
+            xnft: xnft.key(),
+        });
+    }
+
     Ok(())
 }
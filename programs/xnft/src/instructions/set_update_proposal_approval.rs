@@ -0,0 +1,73 @@
+// Copyright (C) 2023 Blue Coral, Inc.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use anchor_lang::prelude::*;
+
+use crate::events::UpdateProposalReviewed;
+use crate::state::{Curator, ProposalStatus, ProtocolConfig, UpdateProposal, Xnft};
+use crate::CustomError;
+
+#[derive(Accounts)]
+pub struct SetUpdateProposalApproval<'info> {
+    #[account(
+        mut,
+        has_one = curator @ CustomError::CuratorMismatch,
+        has_one = xnft,
+        constraint = proposal.status == ProposalStatus::Pending @ CustomError::ProposalNotPending,
+    )]
+    pub proposal: Account<'info, UpdateProposal>,
+
+    #[account(
+        constraint = xnft.curator.as_ref().map(|c| c.pubkey) == Some(curator.key()) @ CustomError::CuratorMismatch,
+        constraint = xnft.curator.as_ref().map_or(false, |c| c.verified) @ CustomError::CuratorNotVerified,
+    )]
+    pub xnft: Account<'info, Xnft>,
+
+    pub curator: Account<'info, Curator>,
+
+    #[account(
+        constraint = curator.is_authorized(authority.key) @ CustomError::CuratorAuthorityMismatch,
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = ["config".as_bytes()],
+        bump = config.bump,
+        constraint = !config.paused @ CustomError::ProtocolPaused,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+}
+
+pub fn set_update_proposal_approval_handler(
+    ctx: Context<SetUpdateProposalApproval>,
+    value: bool,
+) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+
+    proposal.status = if value {
+        ProposalStatus::Approved
+    } else {
+        ProposalStatus::Rejected
+    };
+    proposal.reviewed_ts = Some(Clock::get()?.unix_timestamp);
+
+    emit!(UpdateProposalReviewed {
+        approved: value,
+        curator: ctx.accounts.curator.key(),
+        xnft: ctx.accounts.xnft.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,138 @@
+// Copyright (C) 2023 Blue Coral, Inc.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+use anchor_lang::prelude::*;
+use anchor_spl::metadata::MetadataAccount;
+use anchor_spl::token::TokenAccount as MasterTokenAccount;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::events::InstallationSettled;
+use crate::payments::{InstallPayees, Payment};
+use crate::state::{Escrow, ProtocolConfig, Xnft};
+use crate::CustomError;
+
+#[derive(Accounts)]
+pub struct SettleInstall<'info> {
+    #[account(has_one = install_vault)]
+    pub xnft: Account<'info, Xnft>,
+
+    #[account(
+        associated_token::mint = xnft.master_mint,
+        associated_token::authority = authority,
+        constraint = master_token.amount == 1,
+    )]
+    pub master_token: Account<'info, MasterTokenAccount>,
+
+    /// CHECK: xnft has_one constraint.
+    #[account(mut)]
+    pub install_vault: UncheckedAccount<'info>,
+
+    #[account(address = xnft.master_metadata)]
+    pub master_metadata: Option<Box<Account<'info, MetadataAccount>>>,
+
+    pub install_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub install_vault_token: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: config address constraint.
+    #[account(mut, address = config.treasury)]
+    pub treasury: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub treasury_token: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        close = payer,
+        has_one = payer,
+        has_one = xnft,
+        seeds = [
+            "escrow".as_bytes(),
+            escrow.install.as_ref(),
+            &escrow.edition.to_le_bytes(),
+        ],
+        bump = escrow.bump,
+    )]
+    pub escrow: Account<'info, Escrow>,
+
+    #[account(mut)]
+    pub escrow_token: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: escrow has_one constraint.
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Auto derived below.
+    ////////////////////////////////////////////////////////////////////////////
+    #[account(
+        seeds = ["config".as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    pub authority: Signer<'info>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+impl<'info> SettleInstall<'info> {
+    pub fn payment(&self) -> Result<Payment<'info>> {
+        Payment::try_new_escrow(
+            &self.escrow,
+            self.escrow_token.as_ref(),
+            self.install_mint.as_ref(),
+            self.token_program.as_ref(),
+        )
+    }
+
+    pub fn payees<'a>(&'a self, creators: &'a [AccountInfo<'info>]) -> InstallPayees<'a, 'info> {
+        InstallPayees {
+            creators,
+            fee_basis_points: self.escrow.fee_basis_points,
+            install_vault: self.install_vault.to_account_info(),
+            install_vault_token: self.install_vault_token.as_ref(),
+            master_metadata: self.master_metadata.as_deref(),
+            split: self.escrow.split_install_payments,
+            treasury: self.treasury.to_account_info(),
+            treasury_token: self.treasury_token.as_ref(),
+        }
+    }
+}
+
+pub fn settle_install_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, SettleInstall<'info>>,
+) -> Result<()> {
+    let escrow = &ctx.accounts.escrow;
+
+    require!(
+        !escrow.is_refundable(Clock::get()?.unix_timestamp),
+        CustomError::RefundWindowOpen,
+    );
+
+    // Release the held payment in the same manner as an install without a refund
+    // window, returning the rent of the escrow accounts to the original payer.
+    let payment = ctx.accounts.payment()?;
+    payment.pay_install(ctx.accounts.payees(ctx.remaining_accounts), escrow.amount)?;
+    payment.close(ctx.accounts.payer.to_account_info())?;
+
+    emit!(InstallationSettled {
+        amount: escrow.amount,
+        install: escrow.install,
+        xnft: escrow.xnft,
+    });
+
+    Ok(())
+}
@@ -19,7 +19,7 @@ use anchor_spl::token::{
     self, CloseAccount, FreezeAccount, ThawAccount, Token, TokenAccount, Transfer as TokenTransfer,
 };
 
-use crate::state::{Kind, Xnft};
+use crate::state::{Kind, ProtocolConfig, Xnft};
 use crate::CustomError;
 
 #[derive(Accounts)]
@@ -54,6 +54,13 @@ pub struct Transfer<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
 
+    #[account(
+        seeds = ["config".as_bytes()],
+        bump = config.bump,
+        constraint = !config.paused @ CustomError::ProtocolPaused,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
     pub system_program: Program<'info, System>,
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
@@ -0,0 +1,45 @@
+// Copyright (C) 2023 Blue Coral, Inc.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use anchor_lang::prelude::*;
+
+use crate::state::{Category, ProtocolConfig};
+use crate::CustomError;
+
+#[derive(Accounts)]
+pub struct UpdateCategory<'info> {
+    #[account(
+        seeds = ["config".as_bytes()],
+        bump = config.bump,
+        has_one = admin @ CustomError::ProtocolAdminMismatch,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            "category".as_bytes(),
+            &[category.id],
+        ],
+        bump = category.bump,
+    )]
+    pub category: Account<'info, Category>,
+}
+
+pub fn update_category_handler(ctx: Context<UpdateCategory>, name: String) -> Result<()> {
+    ctx.accounts.category.set_name(name)
+}
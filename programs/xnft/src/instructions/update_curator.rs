@@ -0,0 +1,59 @@
+// Copyright (C) 2023 Blue Coral, Inc.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use anchor_lang::prelude::*;
+
+use crate::state::{Curator, ProtocolConfig, UpdateCuratorParams};
+use crate::CustomError;
+
+#[derive(Accounts)]
+pub struct UpdateCurator<'info> {
+    #[account(
+        mut,
+        has_one = authority,
+    )]
+    pub curator: Account<'info, Curator>,
+
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = ["config".as_bytes()],
+        bump = config.bump,
+        constraint = !config.paused @ CustomError::ProtocolPaused,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+}
+
+pub fn update_curator_handler(
+    ctx: Context<UpdateCurator>,
+    updates: UpdateCuratorParams,
+) -> Result<()> {
+    let curator = &mut ctx.accounts.curator;
+
+    if let Some(authority) = updates.authority {
+        curator.authority = authority;
+    }
+
+    if let Some(members) = updates.members {
+        curator.set_members(members)?;
+    }
+
+    if let Some(uri) = updates.uri {
+        curator.set_uri(uri)?;
+    }
+
+    curator.updated_ts = Clock::get()?.unix_timestamp;
+    Ok(())
+}
@@ -0,0 +1,70 @@
+// Copyright (C) 2023 Blue Coral, Inc.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use anchor_lang::prelude::*;
+use anchor_spl::metadata::MetadataAccount;
+use anchor_spl::token::TokenAccount;
+
+use super::verify_updater;
+use crate::state::{Delegate, ProtocolConfig, Xnft};
+use crate::CustomError;
+
+#[derive(Accounts)]
+pub struct UpdateDelegate<'info> {
+    #[account(has_one = master_metadata)]
+    pub xnft: Account<'info, Xnft>,
+
+    #[account(
+        constraint = master_token.mint == xnft.master_mint,
+        constraint = master_token.amount == 1,
+    )]
+    pub master_token: Account<'info, TokenAccount>,
+
+    pub master_metadata: Account<'info, MetadataAccount>,
+
+    #[account(
+        mut,
+        has_one = xnft,
+    )]
+    pub delegate: Account<'info, Delegate>,
+
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = ["config".as_bytes()],
+        bump = config.bump,
+        constraint = !config.paused @ CustomError::ProtocolPaused,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+}
+
+pub fn update_delegate_handler(ctx: Context<UpdateDelegate>, permissions: u8) -> Result<()> {
+    verify_updater(
+        &ctx.accounts.xnft,
+        &ctx.accounts.master_token,
+        &ctx.accounts.master_metadata,
+        None,
+        ctx.accounts.authority.key,
+        0,
+    )?;
+
+    // Updating the permissions grants them again on behalf of the current owner.
+    let delegate = &mut ctx.accounts.delegate;
+    delegate.set_permissions(permissions)?;
+    delegate.owner = *ctx.accounts.authority.key;
+    delegate.updated_ts = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
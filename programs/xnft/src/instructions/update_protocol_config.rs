@@ -0,0 +1,50 @@
+// Copyright (C) 2023 Blue Coral, Inc.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use anchor_lang::prelude::*;
+
+use crate::state::{ProtocolConfig, UpdateProtocolConfigParams};
+use crate::{CustomError, MAX_FEE_BASIS_POINTS};
+
+#[derive(Accounts)]
+pub struct UpdateProtocolConfig<'info> {
+    #[account(
+        mut,
+        seeds = ["config".as_bytes()],
+        bump = config.bump,
+        has_one = admin @ CustomError::ProtocolAdminMismatch,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+pub fn update_protocol_config_handler(
+    ctx: Context<UpdateProtocolConfig>,
+    updates: UpdateProtocolConfigParams,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    if let Some(fee) = updates.fee_basis_points {
+        require_gte!(MAX_FEE_BASIS_POINTS, fee, CustomError::FeeExceedsMaximum);
+        config.fee_basis_points = fee;
+    }
+
+    if let Some(treasury) = updates.treasury {
+        config.treasury = treasury;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,76 @@
+// Copyright (C) 2023 Blue Coral, Inc.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+use anchor_lang::prelude::*;
+
+use crate::events::ReviewUpdated;
+use crate::state::{ProtocolConfig, Review, Xnft};
+use crate::{CustomError, MAX_RATING, MIN_RATING};
+
+#[derive(Accounts)]
+#[instruction(uri: String)]
+pub struct UpdateReview<'info> {
+    #[account(
+        mut,
+        has_one = author,
+        has_one = xnft,
+        constraint = !review.hidden @ CustomError::ReviewHidden,
+        realloc = Review::len(&uri),
+        realloc::payer = author,
+        realloc::zero = false,
+    )]
+    pub review: Account<'info, Review>,
+
+    #[account(mut)]
+    pub xnft: Account<'info, Xnft>,
+
+    #[account(mut)]
+    pub author: Signer<'info>,
+
+    #[account(
+        seeds = ["config".as_bytes()],
+        bump = config.bump,
+        constraint = !config.paused @ CustomError::ProtocolPaused,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn update_review_handler(ctx: Context<UpdateReview>, uri: String, rating: u8) -> Result<()> {
+    let xnft = &mut ctx.accounts.xnft;
+    let review = &mut ctx.accounts.review;
+
+    if !(MIN_RATING..=MAX_RATING).contains(&rating) {
+        return Err(error!(CustomError::RatingOutOfBounds));
+    }
+
+    // Replace the previous rating of the review in the cumulative rating
+    // and per-star counts of the xNFT without changing the number of ratings.
+    let previous_rating = review.rating;
+    xnft.remove_rating(previous_rating);
+    xnft.add_rating(rating);
+
+    review.rating = rating;
+    review.uri = uri;
+
+    emit!(ReviewUpdated {
+        author: review.author,
+        previous_rating,
+        rating,
+        xnft: xnft.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+// Copyright (C) 2023 Blue Coral, Inc.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::events::ReviewReplyUpdated;
+use crate::state::{ProtocolConfig, ReviewReply, Xnft};
+use crate::CustomError;
+
+#[derive(Accounts)]
+#[instruction(uri: String)]
+pub struct UpdateReviewReply<'info> {
+    #[account(
+        mut,
+        has_one = xnft,
+        seeds = [
+            "reply".as_bytes(),
+            reply.review.as_ref(),
+        ],
+        bump = reply.bump,
+        realloc = ReviewReply::len(&uri),
+        realloc::payer = authority,
+        realloc::zero = false,
+    )]
+    pub reply: Account<'info, ReviewReply>,
+
+    pub xnft: Account<'info, Xnft>,
+
+    #[account(
+        associated_token::mint = xnft.master_mint,
+        associated_token::authority = authority,
+        constraint = master_token.amount == 1,
+    )]
+    pub master_token: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = ["config".as_bytes()],
+        bump = config.bump,
+        constraint = !config.paused @ CustomError::ProtocolPaused,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn update_review_reply_handler(ctx: Context<UpdateReviewReply>, uri: String) -> Result<()> {
+    let reply = &mut ctx.accounts.reply;
+
+    // The reply is attributed to the current master token holder, which
+    // may differ from the original author if the xNFT has been transferred.
+    reply.author = ctx.accounts.authority.key();
+    reply.uri = uri;
+    reply.updated_ts = Clock::get()?.unix_timestamp;
+
+    emit!(ReviewReplyUpdated {
+        author: reply.author,
+        review: reply.review,
+        xnft: reply.xnft,
+    });
+
+    Ok(())
+}
@@ -18,8 +18,10 @@ use anchor_spl::metadata::{self, Metadata, MetadataAccount, UpdateMetadataAccoun
 use anchor_spl::token::TokenAccount;
 use mpl_token_metadata::state::DataV2;
 
-use crate::events::XnftUpdated;
-use crate::state::{CuratorStatus, Kind, UpdateParams, Xnft};
+use crate::events::{ReleasePublished, XnftUpdated};
+use crate::state::{
+    Category, Curator, CuratorStatus, Delegate, Kind, ProtocolConfig, Release, UpdateParams, Xnft,
+};
 use crate::CustomError;
 
 #[derive(Accounts)]
@@ -42,93 +44,250 @@ pub struct UpdateXnft<'info> {
     )]
     pub master_metadata: Account<'info, MetadataAccount>,
 
-    /// CHECK: is validated in the associated token constraint on `master_token`.
+    #[account(
+        seeds = [
+            "delegate".as_bytes(),
+            xnft.key().as_ref(),
+            updater.key().as_ref(),
+        ],
+        bump = delegate.bump,
+    )]
+    pub delegate: Option<Account<'info, Delegate>>,
+
+    pub curator: Option<Account<'info, Curator>>,
+
+    /// CHECK: is validated against the `curator` account in the handler if the xNFT has a verified curator.
     pub curation_authority: UncheckedAccount<'info>,
-    pub updater: Signer<'info>, // TODO: reverse to enable curation
+
+    #[account(
+        init,
+        payer = updater,
+        space = Release::LEN,
+        seeds = [
+            "release".as_bytes(),
+            xnft.key().as_ref(),
+            (xnft.total_releases + 1).to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub release: Option<Account<'info, Release>>,
+
+    #[account(mut)]
+    pub updater: Signer<'info>,
+
+    #[account(
+        seeds = ["config".as_bytes()],
+        bump = config.bump,
+        constraint = !config.paused @ CustomError::ProtocolPaused,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
 
     pub metadata_program: Program<'info, Metadata>,
+    pub system_program: Program<'info, System>,
 }
 
-impl<'info> UpdateXnft<'info> {
-    pub fn update_metadata_accounts_ctx(
-        &self,
-    ) -> CpiContext<'_, '_, '_, 'info, UpdateMetadataAccountsV2<'info>> {
-        let program = self.metadata_program.to_account_info();
-        let accounts = UpdateMetadataAccountsV2 {
-            metadata: self.master_metadata.to_account_info(),
-            update_authority: self.xnft.to_account_info(),
-        };
-        CpiContext::new(program, accounts)
-    }
+pub fn update_xnft_handler(ctx: Context<UpdateXnft>, updates: UpdateParams) -> Result<()> {
+    verify_updater(
+        &ctx.accounts.xnft,
+        &ctx.accounts.master_token,
+        &ctx.accounts.master_metadata,
+        ctx.accounts.delegate.as_ref(),
+        ctx.accounts.updater.key,
+        updates.required_permissions(&ctx.accounts.xnft),
+    )?;
+
+    verify_curation(
+        &ctx.accounts.xnft,
+        ctx.accounts.curator.as_ref(),
+        &ctx.accounts.curation_authority,
+    )?;
+
+    let content_hash = updates.content_hash;
+    let publish = updates.uri.is_some() || content_hash.is_some();
+
+    apply_updates(
+        &mut ctx.accounts.xnft,
+        &ctx.accounts.master_metadata,
+        &ctx.accounts.metadata_program,
+        ctx.remaining_accounts,
+        updates,
+    )?;
+
+    publish_release(
+        &mut ctx.accounts.xnft,
+        ctx.accounts.release.as_mut(),
+        ctx.bumps.get("release").copied(),
+        *ctx.accounts.updater.key,
+        publish,
+        content_hash,
+    )
 }
 
-pub fn update_xnft_handler(ctx: Context<UpdateXnft>, updates: UpdateParams) -> Result<()> {
-    let clock = Clock::get()?;
-    let md = &ctx.accounts.master_metadata;
-
-    // Validate the owner or update authority of the xNFT metadata.
-    match ctx.accounts.xnft.kind {
-        Kind::App => {
-            require_keys_eq!(
-                ctx.accounts.master_token.owner,
-                *ctx.accounts.updater.key,
-                CustomError::UpdateAuthorityMismatch,
-            );
-        }
-        Kind::Collectible => {
-            require_keys_eq!(
-                md.update_authority,
-                *ctx.accounts.updater.key,
-                CustomError::UpdateAuthorityMismatch,
+/// Validates that the updater is the owner of the master token of an app xNFT
+/// or the update authority of the metadata of a collectible xNFT, or otherwise
+/// a delegate of the owner that has been granted the required permissions.
+pub(crate) fn verify_updater(
+    xnft: &Xnft,
+    master_token: &TokenAccount,
+    master_metadata: &MetadataAccount,
+    delegate: Option<&Account<'_, Delegate>>,
+    updater: &Pubkey,
+    permissions: u8,
+) -> Result<()> {
+    let owner = match xnft.kind {
+        Kind::App => master_token.owner,
+        Kind::Collectible => master_metadata.update_authority,
+    };
+
+    verify_owner_or_delegate(
+        &owner,
+        updater,
+        delegate,
+        Some(&owner),
+        permissions,
+        CustomError::UpdateAuthorityMismatch,
+    )
+}
+
+/// Validates that the signer is either the owner of the xNFT or the wallet of
+/// its delegate account, in which case the delegate must have been granted all
+/// of the argued permissions by the current holder of the xNFT. The delegate
+/// account is expected to already be constrained to the xNFT by its seeds.
+pub(crate) fn verify_owner_or_delegate(
+    owner: &Pubkey,
+    signer: &Pubkey,
+    delegate: Option<&Account<'_, Delegate>>,
+    holder: Option<&Pubkey>,
+    permissions: u8,
+    mismatch: CustomError,
+) -> Result<()> {
+    if owner == signer {
+        return Ok(());
+    }
+
+    match delegate {
+        Some(d) if d.wallet == *signer => {
+            // Delegations do not carry over to the next holder of the xNFT.
+            require!(holder == Some(&d.owner), CustomError::DelegateOwnerMismatch,);
+            require!(
+                d.has_permissions(permissions),
+                CustomError::DelegateMissingPermissions,
             );
+            Ok(())
         }
+        _ => Err(error!(mismatch)),
     }
+}
 
-    // Gates the processing of an xNFT update if there is a set curator update authority
-    // on the account that does not match the signer of the transaction.
+/// Gates the processing of an xNFT update if there is a verified curator
+/// on the account whose authority or members did not sign the transaction.
+/// A wallet that was assigned as the curator before `Curator` accounts existed
+/// is omitted as an account and signs as the curation authority itself.
+pub(crate) fn verify_curation(
+    xnft: &Xnft,
+    curator: Option<&Account<'_, Curator>>,
+    curation_authority: &AccountInfo<'_>,
+) -> Result<()> {
     if let Some(CuratorStatus {
         pubkey,
         verified: true,
-    }) = ctx.accounts.xnft.curator
+    }) = xnft.curator
     {
-        require_keys_eq!(
-            pubkey,
-            *ctx.accounts.curation_authority.key,
+        let authorized = match curator {
+            Some(curator) => {
+                require_keys_eq!(pubkey, curator.key(), CustomError::CuratorMismatch);
+                curator.is_authorized(curation_authority.key)
+            }
+            None => *curation_authority.key == pubkey,
+        };
+
+        require!(
+            curation_authority.is_signer && authorized,
             CustomError::CuratorAuthorityMismatch,
         );
     }
+    Ok(())
+}
+
+/// Records the updated URI and content hash of the xNFT as a new release if the
+/// updates changed either of them, in which case the optional release account
+/// must be provided, and must be omitted otherwise.
+pub(crate) fn publish_release<'info>(
+    xnft: &mut Account<'info, Xnft>,
+    release: Option<&mut Account<'info, Release>>,
+    bump: Option<u8>,
+    publisher: Pubkey,
+    publish: bool,
+    content_hash: Option<[u8; 32]>,
+) -> Result<()> {
+    match (publish, release, bump) {
+        (true, Some(release), Some(bump)) => {
+            **release = Release::try_new(xnft, publisher, content_hash, bump)?;
+
+            emit!(ReleasePublished {
+                publisher,
+                version: release.version,
+                xnft: xnft.key(),
+            });
+
+            Ok(())
+        }
+        (false, None, _) => Ok(()),
+        _ => Err(error!(CustomError::ReleaseMismatch)),
+    }
+}
+
+/// Sets the values of the update parameters on the xNFT program account and
+/// propagates a new name or URI to the master metadata of app xNFTs. A new set
+/// of categories must be accompanied by the registered `Category` accounts.
+pub(crate) fn apply_updates<'info>(
+    xnft: &mut Account<'info, Xnft>,
+    master_metadata: &Account<'info, MetadataAccount>,
+    metadata_program: &Program<'info, Metadata>,
+    categories: &[AccountInfo],
+    updates: UpdateParams,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    // A new URI invalidates the digest of the previous bundle, so the content
+    // hash is replaced whenever either of them is updated.
+    if updates.uri.is_some() || updates.content_hash.is_some() {
+        xnft.content_hash = updates.content_hash;
+    }
 
     // Handle update propagation to the Metaplex metadata account is the
     // optional update parameters includes new values for the name or uri.
     if updates.uri.is_some() || updates.name.is_some() {
-        let xnft = &mut ctx.accounts.xnft;
         let uri = updates.uri.unwrap_or_else(|| xnft.uri.clone());
-        xnft.uri = uri.clone();
-
-        if xnft.kind == Kind::App {
-            metadata::update_metadata_accounts_v2(
-                ctx.accounts
-                    .update_metadata_accounts_ctx()
-                    .with_signer(&[&ctx.accounts.xnft.as_seeds()]),
-                None,
-                Some(DataV2 {
-                    name: updates.name.unwrap_or_else(|| md.data.name.clone()),
-                    symbol: md.data.symbol.clone(),
-                    uri,
-                    seller_fee_basis_points: md.data.seller_fee_basis_points,
-                    creators: md.data.creators.clone(),
-                    collection: md.collection.clone(),
-                    uses: md.uses.clone(),
-                }),
-                None,
-                None,
-            )?;
-        }
+        set_uri(xnft, master_metadata, metadata_program, updates.name, uri)?;
     }
 
-    let xnft = &mut ctx.accounts.xnft;
     xnft.install_authority = updates.install_authority;
 
+    // The install mint is only changed when explicitly given, where `Some(None)`
+    // reverts install payments back to lamports.
+    if let Some(mint) = updates.install_mint {
+        xnft.install_mint = mint;
+    }
+
+    // Likewise for the subscription period, where `Some(None)` reverts new
+    // installations of the xNFT back to being permanent.
+    if let Some(period) = updates.subscription_period {
+        if let Some(p) = period {
+            require_gt!(p, 0, CustomError::InvalidSubscriptionPeriod);
+        }
+        xnft.subscription_period = period;
+    }
+
+    // Likewise, a refund window of `Some(None)` removes the escrow of future
+    // install payments, without affecting any payments that are already being held.
+    if let Some(window) = updates.refund_window {
+        if let Some(w) = window {
+            require_gt!(w, 0, CustomError::InvalidRefundWindow);
+        }
+        xnft.refund_window = window;
+    }
+
     // Set other xNFT program account data fields if alternatives
     // were provided in the optional update parameters.
     if let Some(price) = updates.install_price {
@@ -139,8 +298,17 @@ pub fn update_xnft_handler(ctx: Context<UpdateXnft>, updates: UpdateParams) -> R
         xnft.install_vault = vault;
     }
 
-    if let Some(tag) = updates.tag {
-        xnft.tag = tag;
+    if let Some(receipts) = updates.mint_install_receipts {
+        xnft.mint_install_receipts = receipts;
+    }
+
+    if let Some(split) = updates.split_install_payments {
+        xnft.split_install_payments = split;
+    }
+
+    if let Some(c) = updates.categories {
+        Category::verify_registered(c, categories)?;
+        xnft.categories = c;
     }
 
     // Only update the supply if a new supply value was given and
@@ -166,7 +334,50 @@ pub fn update_xnft_handler(ctx: Context<UpdateXnft>, updates: UpdateParams) -> R
 
     xnft.updated_ts = clock.unix_timestamp;
 
-    emit!(XnftUpdated { xnft: xnft.key() });
+    emit!(XnftUpdated {
+        content_hash: xnft.content_hash,
+        xnft: xnft.key(),
+    });
+
+    Ok(())
+}
+
+/// Sets the URI of the xNFT and propagates it, along with an optional new
+/// name, to the master metadata account of app xNFTs.
+pub(crate) fn set_uri<'info>(
+    xnft: &mut Account<'info, Xnft>,
+    master_metadata: &Account<'info, MetadataAccount>,
+    metadata_program: &Program<'info, Metadata>,
+    name: Option<String>,
+    uri: String,
+) -> Result<()> {
+    let md = master_metadata;
+    xnft.uri = uri.clone();
+
+    if xnft.kind == Kind::App {
+        metadata::update_metadata_accounts_v2(
+            CpiContext::new(
+                metadata_program.to_account_info(),
+                UpdateMetadataAccountsV2 {
+                    metadata: md.to_account_info(),
+                    update_authority: xnft.to_account_info(),
+                },
+            )
+            .with_signer(&[&xnft.as_seeds()]),
+            None,
+            Some(DataV2 {
+                name: name.unwrap_or_else(|| md.data.name.clone()),
+                symbol: md.data.symbol.clone(),
+                uri,
+                seller_fee_basis_points: md.data.seller_fee_basis_points,
+                creators: md.data.creators.clone(),
+                collection: md.collection.clone(),
+                uses: md.uses.clone(),
+            }),
+            None,
+            None,
+        )?;
+    }
 
     Ok(())
 }
@@ -0,0 +1,35 @@
+// Copyright (C) 2023 Blue Coral, Inc.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+use anchor_lang::prelude::*;
+
+use crate::state::Install;
+use crate::CustomError;
+
+#[derive(Accounts)]
+pub struct VerifyInstall<'info> {
+    #[account(has_one = xnft)]
+    pub install: Account<'info, Install>,
+
+    /// CHECK: install has_one constraint.
+    pub xnft: UncheckedAccount<'info>,
+}
+
+pub fn verify_install_handler(ctx: Context<VerifyInstall>) -> Result<()> {
+    require!(
+        ctx.accounts.install.is_active(Clock::get()?.unix_timestamp),
+        CustomError::InstallExpired,
+    );
+    Ok(())
+}
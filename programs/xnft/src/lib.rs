@@ -22,7 +22,12 @@ use solana_security_txt::security_txt;
 
 mod events;
 mod instructions;
+pub mod merkle;
+mod payments;
+mod pda;
+mod receipts;
 pub mod state;
+pub mod vouchers;
 
 use instructions::*;
 use state::*;
@@ -39,15 +44,52 @@ security_txt! {
     source_code: "https://github.com/coral-xyz/xnft"
 }
 
+#[constant]
+pub const MAX_CATEGORIES: u8 = 64;
+#[constant]
+pub const MAX_CATEGORY_NAME_LENGTH: usize = 32;
+#[constant]
+pub const MAX_CURATOR_MEMBERS: usize = 10;
+#[constant]
+pub const MAX_FEE_BASIS_POINTS: u16 = 10_000;
 #[constant]
 pub const MAX_RATING: u8 = 5;
 #[constant]
 pub const MIN_RATING: u8 = 1;
+#[constant]
+pub const PERMISSION_CHANGE_PRICE: u8 = 1 << 3;
+#[constant]
+pub const PERMISSION_MANAGE_ACCESS: u8 = 1 << 2;
+#[constant]
+pub const PERMISSION_SUSPEND: u8 = 1 << 1;
+#[constant]
+pub const PERMISSION_UPDATE: u8 = 1 << 0;
 
 #[program]
 pub mod xnft {
     use super::*;
 
+    /// Accepts the pending admin role of the protocol configuration, completing
+    /// the two-step rotation started by `propose_protocol_admin`.
+    pub fn accept_protocol_admin(ctx: Context<AcceptProtocolAdmin>) -> Result<()> {
+        instructions::accept_protocol_admin_handler(ctx)
+    }
+
+    /// Applies the updates of a proposal that was approved by the curator of the
+    /// xNFT, closing the proposal in the process.
+    pub fn apply_update_proposal(ctx: Context<ApplyUpdateProposal>) -> Result<()> {
+        instructions::apply_update_proposal_handler(ctx)
+    }
+
+    /// Creates an "installation" of a private xNFT for a wallet that proves its
+    /// inclusion in the merkle root allowlist of the xNFT, once per wallet.
+    pub fn create_allowlisted_install<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateAllowlistedInstall<'info>>,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::create_allowlisted_install_handler(ctx, proof)
+    }
+
     /// Creates all parts of an xNFT instance.
     /// Once this is invoked, an xNFT exists and can be "installed" by users.
     pub fn create_app_xnft(
@@ -58,6 +100,11 @@ pub mod xnft {
         instructions::create_app_xnft_handler(ctx, name, params)
     }
 
+    /// Registers a new category that xNFTs can be assigned to by its id.
+    pub fn create_category(ctx: Context<CreateCategory>, id: u8, name: String) -> Result<()> {
+        instructions::create_category_handler(ctx, id, name)
+    }
+
     /// Creates an xNFT instance on top of an existing digital collectible that is MPL compliant.
     pub fn create_collectible_xnft(
         ctx: Context<CreateCollectibleXnft>,
@@ -66,16 +113,38 @@ pub mod xnft {
         instructions::create_collectible_xnft_handler(ctx, params)
     }
 
+    /// Creates a curator program account that can be assigned to xNFTs to moderate
+    /// their reviews and gate their updates.
+    pub fn create_curator(
+        ctx: Context<CreateCurator>,
+        name: String,
+        uri: String,
+        members: Vec<Pubkey>,
+    ) -> Result<()> {
+        instructions::create_curator_handler(ctx, name, uri, members)
+    }
+
+    /// Creates a delegate account that allows a wallet to perform the management
+    /// instructions of an xNFT covered by the permission flags on behalf of its owner.
+    pub fn create_delegate(ctx: Context<CreateDelegate>, permissions: u8) -> Result<()> {
+        instructions::create_delegate_handler(ctx, permissions)
+    }
+
     /// Creates an "installation" of an xNFT.
     /// Installation is just a synonym for minting an xNFT edition for a given
-    /// user.
-    pub fn create_install(ctx: Context<CreateInstall>) -> Result<()> {
+    /// user, which is backed by a non-transferable Token-2022 receipt if the
+    /// xNFT mints install receipts.
+    pub fn create_install<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateInstall<'info>>,
+    ) -> Result<()> {
         instructions::create_install_handler(ctx)
     }
 
     /// Creates an "installation" of a private xNFT through prior access approval
     /// granted by the xNFT's installation authority.
-    pub fn create_permissioned_install(ctx: Context<CreatePermissionedInstall>) -> Result<()> {
+    pub fn create_permissioned_install<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreatePermissionedInstall<'info>>,
+    ) -> Result<()> {
         instructions::create_permissioned_install_handler(ctx)
     }
 
@@ -84,16 +153,54 @@ pub mod xnft {
         instructions::create_review_handler(ctx, uri, rating)
     }
 
+    /// Creates a reply to a review of an xNFT, signed by the current
+    /// holder of the xNFT's master token.
+    pub fn create_review_reply(ctx: Context<CreateReviewReply>, uri: String) -> Result<()> {
+        instructions::create_review_reply_handler(ctx, uri)
+    }
+
+    /// Creates an installation of a private xNFT for the signing wallet with a
+    /// voucher that was signed off-chain by the install authority.
+    pub fn create_voucher_install<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateVoucherInstall<'info>>,
+        voucher: InstallVoucher,
+    ) -> Result<()> {
+        instructions::create_voucher_install_handler(ctx, voucher)
+    }
+
+    /// Closes the curator program account once it is not assigned to any xNFTs.
+    pub fn delete_curator(ctx: Context<DeleteCurator>) -> Result<()> {
+        instructions::delete_curator_handler(ctx)
+    }
+
+    /// Closes a delegate account of an xNFT, either by the owner of the xNFT
+    /// or the delegated wallet renouncing its permissions.
+    pub fn delete_delegate(ctx: Context<DeleteDelegate>) -> Result<()> {
+        instructions::delete_delegate_handler(ctx)
+    }
+
     /// Closes the install account.
     pub fn delete_install(ctx: Context<DeleteInstall>) -> Result<()> {
         instructions::delete_install_handler(ctx)
     }
 
-    /// Closes the review account and removes metrics from xNFT account.
+    /// Closes the review account and removes metrics from xNFT account, along
+    /// with any reply to the review.
     pub fn delete_review(ctx: Context<DeleteReview>) -> Result<()> {
         instructions::delete_review_handler(ctx)
     }
 
+    /// Closes the reply to a review of an xNFT.
+    pub fn delete_review_reply(ctx: Context<DeleteReviewReply>) -> Result<()> {
+        instructions::delete_review_reply_handler(ctx)
+    }
+
+    /// Closes an update proposal of an xNFT regardless of its review status,
+    /// as either its proposer or the owner of the xNFT.
+    pub fn delete_update_proposal(ctx: Context<DeleteUpdateProposal>) -> Result<()> {
+        instructions::delete_update_proposal_handler(ctx)
+    }
+
     /// Closes the xNFT and burns the NFT if it meets the criteria for being deletable and optionally burn.
     pub fn delete_xnft(ctx: Context<DeleteXnft>) -> Result<()> {
         instructions::delete_xnft_handler(ctx)
@@ -108,9 +215,83 @@ pub mod xnft {
     }
 
     /// Creates an access program account that indicates a wallet's
-    /// access permission to install a private xNFT.
-    pub fn grant_access(ctx: Context<GrantAccess>) -> Result<()> {
-        instructions::grant_access_handler(ctx)
+    /// access permission to install a private xNFT, optionally limited
+    /// by an expiry timestamp and a number of installs.
+    pub fn grant_access(
+        ctx: Context<GrantAccess>,
+        expires_ts: Option<i64>,
+        max_installs: Option<u32>,
+    ) -> Result<()> {
+        instructions::grant_access_handler(ctx, expires_ts, max_installs)
+    }
+
+    /// Creates access program accounts for each of the wallets to install a
+    /// private xNFT, with their PDAs provided in the same order as remaining accounts.
+    pub fn grant_access_batch<'info>(
+        ctx: Context<'_, '_, '_, 'info, GrantAccessBatch<'info>>,
+        wallets: Vec<Pubkey>,
+        expires_ts: Option<i64>,
+        max_installs: Option<u32>,
+    ) -> Result<()> {
+        instructions::grant_access_batch_handler(ctx, wallets, expires_ts, max_installs)
+    }
+
+    /// Creates the singleton protocol configuration account, signed by the
+    /// upgrade authority of the program who becomes the initial admin.
+    pub fn initialize_protocol_config(
+        ctx: Context<InitializeProtocolConfig>,
+        fee_basis_points: u16,
+    ) -> Result<()> {
+        instructions::initialize_protocol_config_handler(ctx, fee_basis_points)
+    }
+
+    /// Reallocates an xNFT account created with a previous layout to the current
+    /// size of the account, paid for by any signer.
+    pub fn migrate_xnft(ctx: Context<MigrateXnft>) -> Result<()> {
+        instructions::migrate_xnft_handler(ctx)
+    }
+
+    /// Suspends or lifts the suspension of an xNFT as its verified curator or
+    /// the protocol admin, which the owner of the xNFT cannot override.
+    pub fn moderate_xnft(ctx: Context<ModerateXnft>, flag: bool, reason: u8) -> Result<()> {
+        instructions::moderate_xnft_handler(ctx, flag, reason)
+    }
+
+    /// Proposes a new admin for the protocol configuration that must be
+    /// accepted by the proposed wallet before taking effect.
+    pub fn propose_protocol_admin(ctx: Context<ProposeProtocolAdmin>) -> Result<()> {
+        instructions::propose_protocol_admin_handler(ctx)
+    }
+
+    /// Creates a proposal of updates to an xNFT with a verified curator,
+    /// to be reviewed by the curator before it can be applied.
+    pub fn propose_xnft_update(
+        ctx: Context<ProposeXnftUpdate>,
+        updates: UpdateParams,
+    ) -> Result<()> {
+        instructions::propose_xnft_update_handler(ctx, updates)
+    }
+
+    /// Reclaims the escrowed payment of an installation within the refund
+    /// window of the xNFT, closing the `Install` in the process.
+    pub fn refund_install(ctx: Context<RefundInstall>) -> Result<()> {
+        instructions::refund_install_handler(ctx)
+    }
+
+    /// Closes an abusive review of an xNFT and any reply to it, refunding their
+    /// rent to their authors, signed by the verified curator of the xNFT.
+    pub fn remove_review(ctx: Context<RemoveReview>, reason: u8) -> Result<()> {
+        instructions::remove_review_handler(ctx, reason)
+    }
+
+    /// Pays for another subscription period of an installation, extending
+    /// its expiry from the later of the current expiry or now. Any wallet may
+    /// pay to renew on behalf of the installer, but only installations that
+    /// were created as subscriptions can be renewed.
+    pub fn renew_install<'info>(
+        ctx: Context<'_, '_, '_, 'info, RenewInstall<'info>>,
+    ) -> Result<()> {
+        instructions::renew_install_handler(ctx)
     }
 
     /// Closes the access program account for a given wallet on a private xNFT,
@@ -119,7 +300,35 @@ pub mod xnft {
         instructions::revoke_access_handler(ctx)
     }
 
-    /// Assigns a curator public key to the provided xNFT.
+    /// Closes the access program accounts of a private xNFT that are provided
+    /// as remaining accounts.
+    pub fn revoke_access_batch<'info>(
+        ctx: Context<'_, '_, '_, 'info, RevokeAccessBatch<'info>>,
+    ) -> Result<()> {
+        instructions::revoke_access_batch_handler(ctx)
+    }
+
+    /// Closes the installation of an xNFT on behalf of its install authority or
+    /// owner, returning the rent to the wallet that created it. Any receipt of
+    /// the installation stays with the installer, so only the `Install` account
+    /// proves that the installation exists.
+    pub fn revoke_install(ctx: Context<RevokeInstall>) -> Result<()> {
+        instructions::revoke_install_handler(ctx)
+    }
+
+    /// Resets the URI of an xNFT and its metadata to a previously published release,
+    /// subject to the same curator gating as `update_xnft`.
+    pub fn rollback_xnft(ctx: Context<RollbackXnft>) -> Result<()> {
+        instructions::rollback_xnft_handler(ctx)
+    }
+
+    /// Sets or clears the merkle root of the wallets that are allowed to
+    /// install a private xNFT without an access account.
+    pub fn set_allowlist(ctx: Context<SetAllowlist>, root: Option<[u8; 32]>) -> Result<()> {
+        instructions::set_allowlist_handler(ctx, root)
+    }
+
+    /// Assigns a curator program account to the provided xNFT.
     pub fn set_curator(ctx: Context<SetCurator>) -> Result<()> {
         instructions::set_curator_handler(ctx)
     }
@@ -132,9 +341,43 @@ pub mod xnft {
         instructions::set_curator_verification_handler(ctx, value)
     }
 
+    /// Sets or clears the token holdings that are required of a wallet to
+    /// install the xNFT.
+    pub fn set_install_gate(ctx: Context<SetInstallGate>, gate: Option<InstallGate>) -> Result<()> {
+        instructions::set_install_gate_handler(ctx, gate)
+    }
+
+    /// Pauses or unpauses all installs, reviews and updates across the protocol.
+    pub fn set_protocol_paused(ctx: Context<SetProtocolPaused>, paused: bool) -> Result<()> {
+        instructions::set_protocol_paused_handler(ctx, paused)
+    }
+
+    /// Sets the hidden flag on a review of an xNFT, excluding it from the xNFT's
+    /// ratings, signed by the verified curator of the xNFT.
+    pub fn set_review_hidden(ctx: Context<SetReviewHidden>, value: bool, reason: u8) -> Result<()> {
+        instructions::set_review_hidden_handler(ctx, value, reason)
+    }
+
     /// Sets the install suspension flag on the xnft.
-    pub fn set_suspended(ctx: Context<SetSuspended>, flag: bool) -> Result<()> {
-        instructions::set_suspended_handler(ctx, flag)
+    pub fn set_suspended(ctx: Context<SetSuspended>, flag: bool, reason: u8) -> Result<()> {
+        instructions::set_suspended_handler(ctx, flag, reason)
+    }
+
+    /// Approves or rejects a pending update proposal, signed by the authority
+    /// or a member of the xNFT's verified curator.
+    pub fn set_update_proposal_approval(
+        ctx: Context<SetUpdateProposalApproval>,
+        value: bool,
+    ) -> Result<()> {
+        instructions::set_update_proposal_approval_handler(ctx, value)
+    }
+
+    /// Releases the escrowed payment of an installation to the install vault
+    /// or creators of the xNFT once its refund window has passed.
+    pub fn settle_install<'info>(
+        ctx: Context<'_, '_, '_, 'info, SettleInstall<'info>>,
+    ) -> Result<()> {
+        instructions::settle_install_handler(ctx)
     }
 
     /// Transfer the xNFT to the provided designation wallet.
@@ -142,11 +385,53 @@ pub mod xnft {
         instructions::transfer_handler(ctx)
     }
 
+    /// Renames a registered category.
+    pub fn update_category(ctx: Context<UpdateCategory>, name: String) -> Result<()> {
+        instructions::update_category_handler(ctx, name)
+    }
+
+    /// Updates the authority, members and URI of a curator.
+    pub fn update_curator(ctx: Context<UpdateCurator>, updates: UpdateCuratorParams) -> Result<()> {
+        instructions::update_curator_handler(ctx, updates)
+    }
+
+    /// Replaces the permission flags granted to the wallet of a delegate account.
+    pub fn update_delegate(ctx: Context<UpdateDelegate>, permissions: u8) -> Result<()> {
+        instructions::update_delegate_handler(ctx, permissions)
+    }
+
+    /// Updates the fee and treasury of the protocol configuration.
+    pub fn update_protocol_config(
+        ctx: Context<UpdateProtocolConfig>,
+        updates: UpdateProtocolConfigParams,
+    ) -> Result<()> {
+        instructions::update_protocol_config_handler(ctx, updates)
+    }
+
+    /// Updates the URI and rating of a review, reallocating the account
+    /// if the length of the URI has changed.
+    pub fn update_review(ctx: Context<UpdateReview>, uri: String, rating: u8) -> Result<()> {
+        instructions::update_review_handler(ctx, uri, rating)
+    }
+
+    /// Updates the URI of a reply to a review, reallocating the account
+    /// if the length of the URI has changed.
+    pub fn update_review_reply(ctx: Context<UpdateReviewReply>, uri: String) -> Result<()> {
+        instructions::update_review_reply_handler(ctx, uri)
+    }
+
     /// Updates the code of an xNFT.
     /// This is simply a token metadata update cpi.
     pub fn update_xnft(ctx: Context<UpdateXnft>, updates: UpdateParams) -> Result<()> {
         instructions::update_xnft_handler(ctx, updates)
     }
+
+    /// Read-only check that fails if the installation of an xNFT is a
+    /// subscription that has lapsed, intended for use by other programs via CPI.
+    /// Receipts outlive revoked installations, so they cannot replace this check.
+    pub fn verify_install(ctx: Context<VerifyInstall>) -> Result<()> {
+        instructions::verify_install_handler(ctx)
+    }
 }
 
 #[error_code]
@@ -207,4 +492,148 @@ pub enum CustomError {
 
     #[msg("The xNFT is not deletable because its either an app with installations or has reviews")]
     XnftNotDeletable,
+
+    #[msg("The provided mint did not match the install mint of the xNFT")]
+    InstallMintMismatch,
+
+    #[msg("The token accounts required for an SPL token denominated payment were not provided")]
+    MissingPaymentAccounts,
+
+    #[msg("The provided payment token account is not owned by the expected wallet")]
+    PaymentAccountMismatch,
+
+    #[msg("The master metadata account is required to split payments across its creators")]
+    MissingMasterMetadata,
+
+    #[msg("The fee basis points exceed the maximum of 10000")]
+    FeeExceedsMaximum,
+
+    #[msg("The signer is not the pending admin of the protocol")]
+    PendingAdminMismatch,
+
+    #[msg("The signer is not the admin of the protocol")]
+    ProtocolAdminMismatch,
+
+    #[msg("The subscription of the installation has lapsed")]
+    InstallExpired,
+
+    #[msg("The subscription period must be greater than 0 seconds")]
+    InvalidSubscriptionPeriod,
+
+    #[msg("The xNFT is not sold as a subscription")]
+    NotSubscription,
+
+    #[msg("The escrow accounts must be provided only when the install payment is refundable")]
+    EscrowMismatch,
+
+    #[msg("The refund window must be greater than 0 seconds")]
+    InvalidRefundWindow,
+
+    #[msg("The refund window of the installation has passed")]
+    RefundWindowClosed,
+
+    #[msg("The refund window of the installation has not passed")]
+    RefundWindowOpen,
+
+    #[msg("The receipt accounts must be provided only when the xNFT mints install receipts")]
+    ReceiptMismatch,
+
+    #[msg("The assigned curator has not been verified")]
+    CuratorNotVerified,
+
+    #[msg("The review has been hidden by the curator of the xNFT")]
+    ReviewHidden,
+
+    #[msg("The number of curator members exceeds the maximum")]
+    CuratorMembersExceedMax,
+
+    #[msg("The name provided exceeds the maximum length")]
+    NameExceedsMaxLength,
+
+    #[msg("The update proposal has not been approved by the curator")]
+    ProposalNotApproved,
+
+    #[msg("The update proposal has already been reviewed")]
+    ProposalNotPending,
+
+    #[msg("The release account was missing or not expected for the updates")]
+    ReleaseMismatch,
+
+    #[msg("The release is already the current release of the xNFT")]
+    ReleaseIsCurrent,
+
+    #[msg("The xNFT account already has the current layout")]
+    XnftAlreadyMigrated,
+
+    #[msg("The delegate has not been granted the permissions required for the instruction")]
+    DelegateMissingPermissions,
+
+    #[msg("The delegate permissions must be a non-empty combination of the defined flags")]
+    InvalidDelegatePermissions,
+
+    #[msg("The access granted to the wallet has expired")]
+    AccessExpired,
+
+    #[msg("The access granted to the wallet has no installs remaining")]
+    AccessQuotaExhausted,
+
+    #[msg("The access expiry timestamp must be in the future")]
+    InvalidAccessExpiry,
+
+    #[msg("The access install quota must be greater than zero")]
+    InvalidAccessQuota,
+
+    #[msg("The xNFT does not have an allowlist")]
+    AllowlistNotSet,
+
+    #[msg("The merkle proof does not include the wallet in the allowlist")]
+    InvalidAllowlistProof,
+
+    #[msg("The install gate accounts were missing or did not match the gate of the xNFT")]
+    InstallGateAccountMismatch,
+
+    #[msg("The installer does not hold the tokens required by the install gate")]
+    InstallGateNotMet,
+
+    #[msg("A token install gate must require a non-zero amount")]
+    InvalidInstallGate,
+
+    #[msg("The voucher was not signed by the install authority in the preceding instruction")]
+    InvalidVoucherSignature,
+
+    #[msg("The install voucher has expired")]
+    VoucherExpired,
+
+    #[msg("The install voucher was not issued for this wallet and xNFT")]
+    VoucherMismatch,
+
+    #[msg("The remaining accounts did not match the access accounts of the wallets")]
+    AccessAccountMismatch,
+
+    #[msg("The installation payment is still held in escrow")]
+    EscrowNotSettled,
+
+    #[msg("The signer is not an authority of the verified curator or the protocol admin")]
+    ModeratorMismatch,
+
+    #[msg("The xNFT was suspended by a party that the signer cannot override")]
+    SuspensionLocked,
+
+    #[msg("The protocol is paused by its admin")]
+    ProtocolPaused,
+
+    #[msg("The category accounts do not match the ids set in the categories")]
+    CategoryAccountMismatch,
+
+    #[msg("The category id is not below the maximum number of categories")]
+    CategoryIdExceedsMax,
+
+    #[msg("The delegate was not granted by the current owner of the xNFT")]
+    DelegateOwnerMismatch,
+
+    #[msg("The curator is still assigned to xNFTs")]
+    CuratorInUse,
+
+    #[msg("The reply author account does not match the author of the review reply")]
+    ReplyAuthorMismatch,
 }
@@ -0,0 +1,140 @@
+// Copyright (C) 2023 Blue Coral, Inc.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! SHA-256 merkle trees of wallets for xNFT install allowlists.
+//!
+//! Leaves and internal nodes are hashed with distinct prefixes so that a node
+//! cannot be presented as a leaf, and sibling pairs are hashed in sorted order
+//! so that proofs do not need to encode the position of each sibling.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+
+const LEAF_PREFIX: &[u8] = &[0];
+const NODE_PREFIX: &[u8] = &[1];
+
+/// Hashes a wallet public key into a leaf of the allowlist tree.
+pub fn leaf(wallet: &Pubkey) -> [u8; 32] {
+    hashv(&[LEAF_PREFIX, wallet.as_ref()]).to_bytes()
+}
+
+fn node(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (l, r) = if a <= b { (a, b) } else { (b, a) };
+    hashv(&[NODE_PREFIX, l, r]).to_bytes()
+}
+
+/// Whether the proof of sibling hashes leads from the leaf to the root.
+pub fn verify(proof: &[[u8; 32]], root: &[u8; 32], leaf: [u8; 32]) -> bool {
+    proof.iter().fold(leaf, |acc, sibling| node(&acc, sibling)) == *root
+}
+
+/// A merkle tree built off-chain from a list of allowlisted wallets, used to
+/// derive the root stored on an xNFT and the proofs for each of the wallets.
+#[cfg(any(test, feature = "cli"))]
+pub struct MerkleTree {
+    layers: Vec<Vec<[u8; 32]>>,
+}
+
+#[cfg(any(test, feature = "cli"))]
+impl MerkleTree {
+    /// Builds the tree from the wallets, ignoring any duplicates. Layers with an
+    /// odd number of nodes carry their last node up to the next layer unpaired.
+    pub fn new(wallets: &[Pubkey]) -> Self {
+        let mut leaves: Vec<[u8; 32]> = wallets.iter().map(leaf).collect();
+        leaves.sort_unstable();
+        leaves.dedup();
+
+        let mut layers = vec![leaves];
+        while layers.last().unwrap().len() > 1 {
+            let next = layers
+                .last()
+                .unwrap()
+                .chunks(2)
+                .map(|pair| match pair {
+                    [a, b] => node(a, b),
+                    [a] => *a,
+                    _ => unreachable!(),
+                })
+                .collect();
+            layers.push(next);
+        }
+
+        Self { layers }
+    }
+
+    /// The root of the tree, or `None` if it was built without any wallets.
+    pub fn root(&self) -> Option<[u8; 32]> {
+        self.layers.last().unwrap().first().copied()
+    }
+
+    /// The proof of sibling hashes for the wallet, if it is in the tree.
+    pub fn proof(&self, wallet: &Pubkey) -> Option<Vec<[u8; 32]>> {
+        let mut index = self.layers[0].binary_search(&leaf(wallet)).ok()?;
+        let mut proof = Vec::new();
+
+        for layer in &self.layers[..self.layers.len() - 1] {
+            if let Some(sibling) = layer.get(index ^ 1) {
+                proof.push(*sibling);
+            }
+            index /= 2;
+        }
+
+        Some(proof)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn proofs_verify_against_root() {
+        for n in [1, 2, 3, 7, 8, 33] {
+            let wallets: Vec<Pubkey> = (0..n).map(|_| Pubkey::new_unique()).collect();
+            let tree = MerkleTree::new(&wallets);
+            let root = tree.root().unwrap();
+
+            for w in &wallets {
+                let proof = tree.proof(w).unwrap();
+                assert!(verify(&proof, &root, leaf(w)));
+            }
+
+            let outsider = Pubkey::new_unique();
+            assert!(tree.proof(&outsider).is_none());
+            assert!(!verify(
+                &tree.proof(&wallets[0]).unwrap(),
+                &root,
+                leaf(&outsider)
+            ));
+        }
+    }
+
+    #[test]
+    fn empty_tree_has_no_root() {
+        assert!(MerkleTree::new(&[]).root().is_none());
+    }
+
+    #[test]
+    fn nodes_are_not_leaves() {
+        let wallets: Vec<Pubkey> = (0..4).map(|_| Pubkey::new_unique()).collect();
+        let tree = MerkleTree::new(&wallets);
+        let root = tree.root().unwrap();
+
+        // A wallet whose key is an internal node of the tree must not be able
+        // to prove its inclusion with the remaining proof from that node.
+        let inner = Pubkey::new_from_array(tree.layers[1][0]);
+        assert!(!verify(&[tree.layers[1][1]], &root, leaf(&inner)));
+    }
+}
@@ -0,0 +1,562 @@
+// Copyright (C) 2023 Blue Coral, Inc.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use std::collections::BTreeMap;
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::metadata::MetadataAccount;
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::{
+    self, CloseAccount, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::events::InstallationCreated;
+use crate::receipts::Receipt;
+use crate::state::{Escrow, Install, Kind, ProtocolConfig, Xnft};
+use crate::CustomError;
+
+/// The accounts shared by every instruction that creates an installation of an
+/// xNFT, being the installation itself and the accounts of its payment, escrow
+/// and receipt. The `target` is the wallet receiving the installation and the
+/// `authority` is the wallet paying for it.
+#[derive(Accounts)]
+pub struct InstallAccounts<'info> {
+    #[account(
+        mut,
+        has_one = install_vault,
+        constraint = xnft.kind == Kind::App @ CustomError::MustBeApp,
+        constraint = !xnft.suspended @ CustomError::SuspendedInstallation,
+    )]
+    pub xnft: Box<Account<'info, Xnft>>,
+
+    /// CHECK: xnft has_one constraint.
+    #[account(mut)]
+    pub install_vault: UncheckedAccount<'info>,
+
+    #[account(address = xnft.master_metadata)]
+    pub master_metadata: Option<Box<Account<'info, MetadataAccount>>>,
+
+    pub install_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub install_vault_token: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub authority_token: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: config address constraint.
+    #[account(mut, address = config.treasury)]
+    pub treasury: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub treasury_token: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Auto derived below.
+    ////////////////////////////////////////////////////////////////////////////
+    #[account(
+        seeds = ["config".as_bytes()],
+        bump = config.bump,
+        constraint = !config.paused @ CustomError::ProtocolPaused,
+    )]
+    pub config: Box<Account<'info, ProtocolConfig>>,
+
+    #[account(
+        init,
+        payer = target,
+        space = Install::LEN,
+        seeds = [
+            "install".as_bytes(),
+            target.key().as_ref(),
+            xnft.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub install: Box<Account<'info, Install>>,
+
+    #[account(
+        init,
+        payer = target,
+        space = Escrow::LEN,
+        seeds = [
+            "escrow".as_bytes(),
+            install.key().as_ref(),
+            &xnft.total_installs.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub escrow: Option<Box<Account<'info, Escrow>>>,
+
+    #[account(
+        init,
+        payer = target,
+        seeds = [
+            "escrow_token".as_bytes(),
+            install.key().as_ref(),
+            &xnft.total_installs.to_le_bytes(),
+        ],
+        bump,
+        token::mint = install_mint,
+        token::authority = escrow,
+        token::token_program = token_program,
+    )]
+    pub escrow_token: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// CHECK: initialized as a Token-2022 mint when minting the receipt.
+    #[account(
+        mut,
+        seeds = [
+            "receipt".as_bytes(),
+            xnft.key().as_ref(),
+            &xnft.total_installs.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub receipt_mint: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: created as an associated token account when minting the receipt.
+    #[account(mut)]
+    pub receipt_token: Option<UncheckedAccount<'info>>,
+
+    #[account(mut)]
+    pub target: Signer<'info>,
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    pub receipt_token_program: Option<Program<'info, Token2022>>,
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+}
+
+impl<'info> InstallAccounts<'info> {
+    pub fn payment(&self) -> Result<Payment<'info>> {
+        Payment::try_new(
+            self.xnft.install_mint,
+            self.authority.to_account_info(),
+            self.authority_token.as_ref(),
+            self.install_mint.as_ref(),
+            self.system_program.to_account_info(),
+            self.token_program.as_ref(),
+        )
+    }
+
+    pub fn receipt(&self) -> Option<Receipt<'info>> {
+        match (
+            &self.receipt_mint,
+            &self.receipt_token,
+            &self.receipt_token_program,
+            &self.associated_token_program,
+        ) {
+            (Some(mint), Some(token), Some(token_program), Some(associated_token_program)) => {
+                Some(Receipt {
+                    associated_token_program: associated_token_program.to_account_info(),
+                    mint: mint.to_account_info(),
+                    owner: self.target.to_account_info(),
+                    payer: self.target.to_account_info(),
+                    system_program: self.system_program.to_account_info(),
+                    token: token.to_account_info(),
+                    token_program: token_program.to_account_info(),
+                    xnft: self.xnft.to_account_info(),
+                })
+            }
+            _ => None,
+        }
+    }
+
+    pub fn payees<'a>(&'a self, creators: &'a [AccountInfo<'info>]) -> InstallPayees<'a, 'info> {
+        InstallPayees {
+            creators,
+            fee_basis_points: self.config.fee_basis_points,
+            install_vault: self.install_vault.to_account_info(),
+            install_vault_token: self.install_vault_token.as_ref(),
+            master_metadata: self.master_metadata.as_deref(),
+            split: self.xnft.split_install_payments,
+            treasury: self.treasury.to_account_info(),
+            treasury_token: self.treasury_token.as_ref(),
+        }
+    }
+
+    /// Pays for and records the installation of the xNFT for the target wallet
+    /// once the calling instruction has verified the wallet may install it.
+    ///
+    /// The payment is either held in the escrow of the installation until its
+    /// refund window has passed or the protocol fee is taken into the treasury and
+    /// the remainder paid directly into the install vault or split across the
+    /// `creators`. A non-transferable receipt is minted for the edition if enabled.
+    pub fn create(
+        &mut self,
+        creators: &[AccountInfo<'info>],
+        bumps: &BTreeMap<String, u8>,
+    ) -> Result<()> {
+        let xnft = &self.xnft;
+        xnft.verify_supply()?;
+
+        // The escrow accounts are only expected if the install payment is refundable.
+        let escrowed = xnft.install_price > 0 && xnft.refund_window.is_some();
+        require_eq!(self.escrow.is_some(), escrowed, CustomError::EscrowMismatch);
+
+        if xnft.install_price > 0 {
+            let payment = self.payment()?;
+
+            if escrowed {
+                let escrow = payment.destination(
+                    self.escrow.as_ref().unwrap().to_account_info(),
+                    self.escrow_token.as_deref(),
+                )?;
+                payment.transfer(escrow, xnft.install_price)?;
+            } else {
+                payment.pay_install(self.payees(creators), xnft.install_price)?;
+            }
+        }
+
+        // Initialize the install data.
+        **self.install = Install::try_new(&mut self.xnft, self.target.key)?;
+
+        // The split and fee of an escrowed payment are fixed at the time of
+        // installation, regardless of later changes to the xNFT or protocol.
+        if let Some(escrow) = self.escrow.as_mut() {
+            ***escrow = Escrow::try_new(
+                &self.install,
+                &self.xnft,
+                self.authority.key(),
+                self.config.fee_basis_points,
+                *bumps.get("escrow").unwrap(),
+            )?;
+        }
+
+        // Mint a non-transferable receipt token for the edition to the installer, if enabled.
+        let receipt = self.receipt();
+        require_eq!(
+            receipt.is_some(),
+            self.xnft.mint_install_receipts,
+            CustomError::ReceiptMismatch,
+        );
+
+        if let Some(r) = receipt {
+            r.mint(
+                self.install.edition,
+                *bumps.get("receipt_mint").unwrap(),
+                &self.xnft.as_seeds(),
+            )?;
+            self.install.receipt_mint = Some(r.mint.key());
+        }
+
+        emit!(InstallationCreated {
+            installer: self.target.key(),
+            xnft: self.xnft.key(),
+        });
+
+        Ok(())
+    }
+}
+
+/// The accounts that receive the funds of an install payment for an xNFT.
+pub struct InstallPayees<'a, 'info> {
+    pub creators: &'a [AccountInfo<'info>],
+    pub install_vault: AccountInfo<'info>,
+    pub install_vault_token: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+    pub fee_basis_points: u16,
+    pub master_metadata: Option<&'a Account<'info, MetadataAccount>>,
+    pub split: bool,
+    pub treasury: AccountInfo<'info>,
+    pub treasury_token: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+}
+
+/// The source of funds for a payment made to an xNFT, denominated either
+/// in lamports or in the SPL token (or Token-2022) mint set on the account.
+pub enum Payment<'info> {
+    Escrow {
+        from: AccountInfo<'info>,
+    },
+    Lamports {
+        from: AccountInfo<'info>,
+        system_program: AccountInfo<'info>,
+    },
+    Token {
+        authority: AccountInfo<'info>,
+        decimals: u8,
+        from: AccountInfo<'info>,
+        mint: AccountInfo<'info>,
+        signer_seeds: Vec<Vec<u8>>,
+        token_program: AccountInfo<'info>,
+    },
+}
+
+impl<'info> Payment<'info> {
+    /// Builds the payment source for the argued signer based on the optional
+    /// `install_mint` of an xNFT. If a mint is set, the token accounts and program
+    /// must have been provided to the instruction.
+    pub fn try_new(
+        install_mint: Option<Pubkey>,
+        authority: AccountInfo<'info>,
+        authority_token: Option<&InterfaceAccount<'info, TokenAccount>>,
+        mint: Option<&InterfaceAccount<'info, Mint>>,
+        system_program: AccountInfo<'info>,
+        token_program: Option<&Interface<'info, TokenInterface>>,
+    ) -> Result<Self> {
+        match install_mint {
+            Some(m) => Self::try_new_token(m, authority, authority_token, mint, token_program),
+            None => Ok(Self::Lamports {
+                from: authority,
+                system_program,
+            }),
+        }
+    }
+
+    /// Builds the payment source for the funds held by an install escrow, which
+    /// signs for the transfer out of its token account with its PDA seeds.
+    pub fn try_new_escrow(
+        escrow: &Account<'info, Escrow>,
+        escrow_token: Option<&InterfaceAccount<'info, TokenAccount>>,
+        mint: Option<&InterfaceAccount<'info, Mint>>,
+        token_program: Option<&Interface<'info, TokenInterface>>,
+    ) -> Result<Self> {
+        match escrow.mint {
+            Some(m) => {
+                let mut payment = Self::try_new_token(
+                    m,
+                    escrow.to_account_info(),
+                    escrow_token,
+                    mint,
+                    token_program,
+                )?;
+                if let Self::Token { signer_seeds, .. } = &mut payment {
+                    *signer_seeds = escrow.as_seeds().into();
+                }
+                Ok(payment)
+            }
+            None => Ok(Self::Escrow {
+                from: escrow.to_account_info(),
+            }),
+        }
+    }
+
+    fn try_new_token(
+        expected_mint: Pubkey,
+        authority: AccountInfo<'info>,
+        from: Option<&InterfaceAccount<'info, TokenAccount>>,
+        mint: Option<&InterfaceAccount<'info, Mint>>,
+        token_program: Option<&Interface<'info, TokenInterface>>,
+    ) -> Result<Self> {
+        match (mint, from, token_program) {
+            (Some(m), Some(t), Some(p)) => {
+                require_keys_eq!(m.key(), expected_mint, CustomError::InstallMintMismatch);
+
+                Ok(Self::Token {
+                    authority,
+                    decimals: m.decimals,
+                    from: t.to_account_info(),
+                    mint: m.to_account_info(),
+                    signer_seeds: Vec::new(),
+                    token_program: p.to_account_info(),
+                })
+            }
+            _ => Err(error!(CustomError::MissingPaymentAccounts)),
+        }
+    }
+
+    /// Closes the token account that an escrowed payment was made from, returning
+    /// its rent to the receiver. Escrowed lamports are closed with the escrow itself.
+    pub fn close(&self, receiver: AccountInfo<'info>) -> Result<()> {
+        match self {
+            Self::Token {
+                authority,
+                from,
+                signer_seeds,
+                token_program,
+                ..
+            } if !signer_seeds.is_empty() => {
+                let seeds: Vec<&[u8]> = signer_seeds.iter().map(|s| s.as_slice()).collect();
+                token_interface::close_account(CpiContext::new_with_signer(
+                    token_program.clone(),
+                    CloseAccount {
+                        account: from.clone(),
+                        authority: authority.clone(),
+                        destination: receiver,
+                    },
+                    &[&seeds],
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Picks the account that should receive funds from this payment source,
+    /// being the wallet itself for lamports or the wallet's token account for the mint.
+    pub fn destination(
+        &self,
+        wallet: AccountInfo<'info>,
+        token: Option<&InterfaceAccount<'info, TokenAccount>>,
+    ) -> Result<AccountInfo<'info>> {
+        match self {
+            Self::Escrow { .. } | Self::Lamports { .. } => Ok(wallet),
+            Self::Token { .. } => {
+                let t = token.ok_or(error!(CustomError::MissingPaymentAccounts))?;
+                require_keys_eq!(t.owner, wallet.key(), CustomError::PaymentAccountMismatch);
+                Ok(t.to_account_info())
+            }
+        }
+    }
+
+    /// Pays the price of an install, taking the protocol fee into the treasury and
+    /// the remainder either directly into the install vault or split across the creators.
+    pub fn pay_install(&self, payees: InstallPayees<'_, 'info>, price: u64) -> Result<()> {
+        let fee = ProtocolConfig::fee(price, payees.fee_basis_points);
+
+        if fee > 0 {
+            let treasury = self.destination(payees.treasury, payees.treasury_token)?;
+            self.transfer(treasury, fee)?;
+        }
+
+        let remainder = price - fee;
+
+        if payees.split {
+            self.split(payees.master_metadata, payees.creators, remainder)
+        } else {
+            let vault = self.destination(payees.install_vault, payees.install_vault_token)?;
+            self.transfer(vault, remainder)
+        }
+    }
+
+    /// Splits the amount across the creators listed in the metadata account proportional
+    /// to their shares. The `accounts` are expected to be each creator's wallet (or their
+    /// token account for the payment mint) in the same order as the creators list.
+    pub fn split(
+        &self,
+        metadata: Option<&Account<'info, MetadataAccount>>,
+        accounts: &[AccountInfo<'info>],
+        amount: u64,
+    ) -> Result<()> {
+        let metadata = metadata.ok_or(error!(CustomError::MissingMasterMetadata))?;
+        let creators = metadata
+            .data
+            .creators
+            .as_ref()
+            .ok_or(error!(CustomError::UnknownCreator))?;
+
+        require_eq!(creators.len(), accounts.len());
+
+        let shares: Vec<u8> = creators.iter().map(|c| c.share).collect();
+        let portions = creator_portions(amount, &shares)?;
+
+        for ((c, info), portion) in creators.iter().zip(accounts).zip(portions) {
+            let owner = match self {
+                Self::Escrow { .. } | Self::Lamports { .. } => *info.key,
+                Self::Token { .. } => InterfaceAccount::<TokenAccount>::try_from(info)?.owner,
+            };
+            require_keys_eq!(c.address, owner, CustomError::UnknownCreator);
+
+            self.transfer(info.clone(), portion)?;
+        }
+
+        Ok(())
+    }
+
+    /// Moves the amount from the payment source into the destination account.
+    pub fn transfer(&self, to: AccountInfo<'info>, amount: u64) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+
+        match self {
+            Self::Escrow { from } => {
+                **from.try_borrow_mut_lamports()? -= amount;
+                **to.try_borrow_mut_lamports()? += amount;
+                Ok(())
+            }
+            Self::Lamports {
+                from,
+                system_program,
+            } => system_program::transfer(
+                CpiContext::new(
+                    system_program.clone(),
+                    system_program::Transfer {
+                        from: from.clone(),
+                        to,
+                    },
+                ),
+                amount,
+            ),
+            Self::Token {
+                authority,
+                decimals,
+                from,
+                mint,
+                signer_seeds,
+                token_program,
+            } => {
+                let seeds: Vec<&[u8]> = signer_seeds.iter().map(|s| s.as_slice()).collect();
+                let signer = [seeds.as_slice()];
+                token_interface::transfer_checked(
+                    CpiContext::new(
+                        token_program.clone(),
+                        TransferChecked {
+                            authority: authority.clone(),
+                            from: from.clone(),
+                            mint: mint.clone(),
+                            to,
+                        },
+                    )
+                    .with_signer(if seeds.is_empty() { &[] } else { &signer }),
+                    amount,
+                    *decimals,
+                )
+            }
+        }
+    }
+}
+
+/// Calculates the portion of the amount owed to each creator share. Any dust
+/// left over from the integer division is given to the first creator so that
+/// the entire amount is always paid out.
+pub fn creator_portions(amount: u64, shares: &[u8]) -> Result<Vec<u64>> {
+    require!(!shares.is_empty(), CustomError::UnknownCreator);
+
+    let total_shares: u16 = shares.iter().map(|s| *s as u16).sum();
+    require_gte!(100, total_shares);
+
+    let mut portions: Vec<u64> = shares
+        .iter()
+        .map(|s| (amount as u128 * *s as u128 / 100) as u64)
+        .collect();
+
+    let total: u64 = portions.iter().sum();
+    portions[0] += amount - total;
+
+    Ok(portions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::creator_portions;
+
+    #[test]
+    fn creator_portions_pays_full_amount() {
+        assert_eq!(creator_portions(100, &[50, 50]).unwrap(), vec![50, 50]);
+        assert_eq!(creator_portions(101, &[50, 50]).unwrap(), vec![51, 50]);
+        assert_eq!(creator_portions(10, &[33, 33, 34]).unwrap(), vec![4, 3, 3]);
+        assert_eq!(creator_portions(7, &[0, 100]).unwrap(), vec![0, 7]);
+        assert_eq!(creator_portions(u64::MAX, &[100]).unwrap(), vec![u64::MAX]);
+    }
+
+    #[test]
+    fn creator_portions_rejects_invalid_shares() {
+        assert!(creator_portions(100, &[]).is_err());
+        assert!(creator_portions(100, &[101]).is_err());
+        assert!(creator_portions(u64::MAX, &[60, 60]).is_err());
+    }
+}
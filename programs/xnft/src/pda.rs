@@ -0,0 +1,247 @@
+// Copyright (C) 2023 Blue Coral, Inc.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Creation of program derived accounts outside of Anchor's `init` constraint,
+//! for accounts whose addresses are only known while processing an instruction.
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Allocate, Assign, CreateAccount, Transfer};
+
+/// Creates the program derived account with the space and owner, signed for
+/// with its seeds, in the same manner as Anchor's `init` constraint.
+///
+/// Since the addresses are predictable, anyone can transfer lamports to one
+/// before it is created, which would make the system program refuse to create
+/// it. An account that already holds lamports is instead topped up to be rent
+/// exempt before being allocated and assigned to the owner.
+pub fn create<'info>(
+    payer: &AccountInfo<'info>,
+    account: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    owner: &Pubkey,
+    seeds: &[&[u8]],
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(space);
+    let lamports = account.lamports();
+
+    if lamports == 0 {
+        return system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                CreateAccount {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+                &[seeds],
+            ),
+            rent,
+            space as u64,
+            owner,
+        );
+    }
+
+    let shortfall = rent.saturating_sub(lamports);
+    if shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Allocate {
+                account_to_allocate: account.clone(),
+            },
+            &[seeds],
+        ),
+        space as u64,
+    )?;
+
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Assign {
+                account_to_assign: account.clone(),
+            },
+            &[seeds],
+        ),
+        owner,
+    )
+}
+
+/// Syscall stubs for unit tests that provide the default rent sysvar and record
+/// the instructions invoked through CPI on the calling test's thread.
+#[cfg(test)]
+pub(crate) mod stubs {
+    use std::cell::RefCell;
+    use std::sync::Once;
+
+    use anchor_lang::solana_program::entrypoint::{ProgramResult, SUCCESS};
+    use anchor_lang::solana_program::instruction::Instruction;
+    use anchor_lang::solana_program::program_stubs::{set_syscall_stubs, SyscallStubs};
+
+    use super::*;
+
+    thread_local! {
+        static INVOKED: RefCell<Vec<Instruction>> = RefCell::new(Vec::new());
+    }
+
+    struct RecordingStubs;
+
+    impl SyscallStubs for RecordingStubs {
+        fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+            unsafe { *(var_addr as *mut Rent) = Rent::default() };
+            SUCCESS
+        }
+
+        fn sol_invoke_signed(
+            &self,
+            instruction: &Instruction,
+            _account_infos: &[AccountInfo],
+            _signers_seeds: &[&[&[u8]]],
+        ) -> ProgramResult {
+            INVOKED.with(|i| i.borrow_mut().push(instruction.clone()));
+            Ok(())
+        }
+    }
+
+    /// Installs the stubs and clears the instructions recorded on this thread.
+    pub fn install() {
+        static INIT: Once = Once::new();
+        INIT.call_once(|| {
+            set_syscall_stubs(Box::new(RecordingStubs));
+        });
+        INVOKED.with(|i| i.borrow_mut().clear());
+    }
+
+    /// Takes the instructions invoked on this thread since they were last taken.
+    pub fn invoked() -> Vec<Instruction> {
+        INVOKED.with(|i| i.take())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::solana_program::system_instruction;
+
+    use super::*;
+
+    const SPACE: usize = 100;
+
+    fn create_with_lamports(mut lamports: u64) -> (Pubkey, Pubkey, u64) {
+        let payer_key = Pubkey::new_unique();
+        let account_key = Pubkey::new_unique();
+        let system_key = system_program::ID;
+        let owner = crate::ID;
+
+        let mut payer_lamports = 1_000_000_000;
+        let mut system_lamports = 0;
+        let (mut payer_data, mut account_data, mut system_data) = ([], [], []);
+
+        let payer = AccountInfo::new(
+            &payer_key,
+            true,
+            true,
+            &mut payer_lamports,
+            &mut payer_data,
+            &system_key,
+            false,
+            0,
+        );
+        let account = AccountInfo::new(
+            &account_key,
+            false,
+            true,
+            &mut lamports,
+            &mut account_data,
+            &system_key,
+            false,
+            0,
+        );
+        let system = AccountInfo::new(
+            &system_key,
+            false,
+            false,
+            &mut system_lamports,
+            &mut system_data,
+            &system_key,
+            true,
+            0,
+        );
+
+        create(&payer, &account, &system, SPACE, &owner, &[b"seed"]).unwrap();
+        (
+            payer_key,
+            account_key,
+            Rent::default().minimum_balance(SPACE),
+        )
+    }
+
+    #[test]
+    fn creates_unfunded_account() {
+        stubs::install();
+        let (payer, account, rent) = create_with_lamports(0);
+
+        assert_eq!(
+            stubs::invoked(),
+            vec![system_instruction::create_account(
+                &payer,
+                &account,
+                rent,
+                SPACE as u64,
+                &crate::ID,
+            )],
+        );
+    }
+
+    #[test]
+    fn tops_up_prefunded_account() {
+        stubs::install();
+        let (payer, account, rent) = create_with_lamports(1);
+
+        assert_eq!(
+            stubs::invoked(),
+            vec![
+                system_instruction::transfer(&payer, &account, rent - 1),
+                system_instruction::allocate(&account, SPACE as u64),
+                system_instruction::assign(&account, &crate::ID),
+            ],
+        );
+    }
+
+    #[test]
+    fn allocates_rent_exempt_account() {
+        stubs::install();
+        let rent = Rent::default().minimum_balance(SPACE);
+        let (_, account, _) = create_with_lamports(rent + 1);
+
+        assert_eq!(
+            stubs::invoked(),
+            vec![
+                system_instruction::allocate(&account, SPACE as u64),
+                system_instruction::assign(&account, &crate::ID),
+            ],
+        );
+    }
+}
@@ -0,0 +1,193 @@
+// Copyright (C) 2023 Blue Coral, Inc.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke;
+use anchor_spl::associated_token::{self, Create};
+use anchor_spl::token_2022::spl_token_2022::extension::ExtensionType;
+use anchor_spl::token_2022::spl_token_2022::instruction::AuthorityType;
+use anchor_spl::token_2022::spl_token_2022::{self, state::Mint};
+use anchor_spl::token_2022::{self, InitializeMint2, MintTo, SetAuthority};
+
+use crate::pda;
+
+/// The accounts used to mint a non-transferable Token-2022 receipt of an
+/// installation from the xNFT to the wallet receiving the installation.
+///
+/// The receipt cannot be burned by the program once minted, so it outlives the
+/// `Install` account if the installation is deleted or revoked.
+pub struct Receipt<'info> {
+    pub associated_token_program: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+    pub owner: AccountInfo<'info>,
+    pub payer: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub token: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub xnft: AccountInfo<'info>,
+}
+
+impl<'info> Receipt<'info> {
+    /// Creates the receipt mint for the edition with the non-transferable extension,
+    /// mints the single receipt token to the owner's associated token account and
+    /// then removes the mint authority so that no other tokens can be minted.
+    pub fn mint(&self, edition: u64, mint_bump: u8, xnft_seeds: &[&[u8]]) -> Result<()> {
+        let edition_bytes = edition.to_le_bytes();
+        let mint_seeds: &[&[u8]] = &[
+            "receipt".as_bytes(),
+            self.xnft.key.as_ref(),
+            &edition_bytes,
+            &[mint_bump],
+        ];
+
+        let space = ExtensionType::get_account_len::<Mint>(&[ExtensionType::NonTransferable]);
+        pda::create(
+            &self.payer,
+            &self.mint,
+            &self.system_program,
+            space,
+            self.token_program.key,
+            mint_seeds,
+        )?;
+
+        invoke(
+            &spl_token_2022::instruction::initialize_non_transferable_mint(
+                self.token_program.key,
+                self.mint.key,
+            )?,
+            &[self.mint.clone()],
+        )?;
+
+        token_2022::initialize_mint2(
+            CpiContext::new(
+                self.token_program.clone(),
+                InitializeMint2 {
+                    mint: self.mint.clone(),
+                },
+            ),
+            0,
+            self.xnft.key,
+            None,
+        )?;
+
+        associated_token::create(CpiContext::new(
+            self.associated_token_program.clone(),
+            Create {
+                associated_token: self.token.clone(),
+                authority: self.owner.clone(),
+                mint: self.mint.clone(),
+                payer: self.payer.clone(),
+                system_program: self.system_program.clone(),
+                token_program: self.token_program.clone(),
+            },
+        ))?;
+
+        token_2022::mint_to(
+            CpiContext::new_with_signer(
+                self.token_program.clone(),
+                MintTo {
+                    authority: self.xnft.clone(),
+                    mint: self.mint.clone(),
+                    to: self.token.clone(),
+                },
+                &[xnft_seeds],
+            ),
+            1,
+        )?;
+
+        token_2022::set_authority(
+            CpiContext::new_with_signer(
+                self.token_program.clone(),
+                SetAuthority {
+                    account_or_mint: self.mint.clone(),
+                    current_authority: self.xnft.clone(),
+                },
+                &[xnft_seeds],
+            ),
+            AuthorityType::MintTokens,
+            None,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::solana_program::system_instruction;
+
+    use super::*;
+    use crate::pda::stubs;
+
+    #[test]
+    fn mints_receipt_to_prefunded_mint() {
+        stubs::install();
+
+        let keys = [
+            anchor_spl::associated_token::ID,
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            anchor_lang::system_program::ID,
+            Pubkey::new_unique(),
+            spl_token_2022::ID,
+            Pubkey::new_unique(),
+        ];
+        let mut lamports = [0, 1, 0, 1_000_000_000, 0, 0, 0, 0];
+        let mut data = vec![Vec::new(); keys.len()];
+        let infos: Vec<AccountInfo> = keys
+            .iter()
+            .zip(lamports.iter_mut())
+            .zip(data.iter_mut())
+            .map(|((key, lamports), data)| {
+                AccountInfo::new(
+                    key,
+                    false,
+                    true,
+                    lamports,
+                    data,
+                    &anchor_lang::system_program::ID,
+                    false,
+                    0,
+                )
+            })
+            .collect();
+
+        let receipt = Receipt {
+            associated_token_program: infos[0].clone(),
+            mint: infos[1].clone(),
+            owner: infos[2].clone(),
+            payer: infos[3].clone(),
+            system_program: infos[4].clone(),
+            token: infos[5].clone(),
+            token_program: infos[6].clone(),
+            xnft: infos[7].clone(),
+        };
+        receipt.mint(0, 255, &[b"xnft"]).unwrap();
+
+        let space = ExtensionType::get_account_len::<Mint>(&[ExtensionType::NonTransferable]);
+        let invoked = stubs::invoked();
+        assert_eq!(invoked.len(), 8);
+        assert_eq!(
+            invoked[..3],
+            [
+                system_instruction::transfer(
+                    &keys[3],
+                    &keys[1],
+                    Rent::default().minimum_balance(space) - 1,
+                ),
+                system_instruction::allocate(&keys[1], space as u64),
+                system_instruction::assign(&keys[1], &spl_token_2022::ID),
+            ],
+        );
+    }
+}
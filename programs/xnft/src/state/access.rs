@@ -15,6 +15,8 @@
 
 use anchor_lang::prelude::*;
 
+use crate::CustomError;
+
 #[account]
 pub struct Access {
     /// The pubkey of the wallet being granted access (32).
@@ -23,29 +25,115 @@ pub struct Access {
     pub xnft: Pubkey,
     /// Bump nonce of the PDA (1).
     pub bump: u8,
+    /// The optional unix timestamp after which the access can no longer be used (9).
+    pub expires_ts: Option<i64>,
+    /// The maximum number of installs the access can be used for, where `0`
+    /// is treated as a single install for accounts granted before quotas (4).
+    pub max_installs: u32,
+    /// The number of installs that the access has been used for (4).
+    pub total_installs: u32,
     /// Unused reserved byte space for additive future changes.
-    pub _reserved: [u8; 32],
+    pub _reserved: [u8; 15],
 }
 
 impl Access {
-    pub const LEN: usize = 8 + (32 * 2) + 1 + 32;
+    pub const LEN: usize = 8 + (32 * 2) + 1 + 9 + 4 + 4 + 15;
+
+    pub fn try_new(
+        wallet: Pubkey,
+        xnft: Pubkey,
+        bump: u8,
+        expires_ts: Option<i64>,
+        max_installs: Option<u32>,
+    ) -> Result<Self> {
+        if let Some(ts) = expires_ts {
+            require_gt!(
+                ts,
+                Clock::get()?.unix_timestamp,
+                CustomError::InvalidAccessExpiry,
+            );
+        }
 
-    pub fn new(wallet: Pubkey, xnft: Pubkey, bump: u8) -> Self {
-        Self {
+        // Access without a quota can be reused until it is revoked or expires.
+        let max_installs = max_installs.unwrap_or(u32::MAX);
+        require_gt!(max_installs, 0, CustomError::InvalidAccessQuota);
+
+        Ok(Self {
             bump,
             wallet,
             xnft,
-            _reserved: [0; 32],
+            expires_ts,
+            max_installs,
+            total_installs: 0,
+            _reserved: [0; 15],
+        })
+    }
+
+    /// Whether the quota of installs for the access has been used up.
+    pub fn is_exhausted(&self) -> bool {
+        self.total_installs >= self.max_installs.max(1)
+    }
+
+    /// Records an install made with the access after verifying that it has
+    /// not expired or run out of installs.
+    pub fn use_install(&mut self, now: i64) -> Result<()> {
+        if let Some(ts) = self.expires_ts {
+            require_gt!(ts, now, CustomError::AccessExpired);
         }
+        require!(!self.is_exhausted(), CustomError::AccessQuotaExhausted);
+
+        self.total_installs += 1;
+        Ok(())
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Access;
+    use super::*;
+
+    fn access(expires_ts: Option<i64>, max_installs: u32) -> Access {
+        Access {
+            wallet: Pubkey::new_unique(),
+            xnft: Pubkey::new_unique(),
+            bump: 0,
+            expires_ts,
+            max_installs,
+            total_installs: 0,
+            _reserved: [0; 15],
+        }
+    }
 
     #[test]
     fn account_size_matches() {
         assert_eq!(Access::LEN, 105);
     }
+
+    #[test]
+    fn install_quota() {
+        let mut a = access(None, 2);
+        a.use_install(0).unwrap();
+        assert!(!a.is_exhausted());
+        a.use_install(0).unwrap();
+        assert!(a.is_exhausted());
+        assert_eq!(
+            a.use_install(0).unwrap_err(),
+            anchor_lang::error::Error::from(CustomError::AccessQuotaExhausted),
+        );
+
+        // Accounts granted before quotas were introduced are single-use.
+        let mut legacy = access(None, 0);
+        legacy.use_install(0).unwrap();
+        assert!(legacy.is_exhausted());
+    }
+
+    #[test]
+    fn access_expiry() {
+        let mut a = access(Some(100), u32::MAX);
+        a.use_install(99).unwrap();
+        assert!(!a.is_exhausted());
+        assert_eq!(
+            a.use_install(100).unwrap_err(),
+            anchor_lang::error::Error::from(CustomError::AccessExpired),
+        );
+    }
 }
@@ -0,0 +1,54 @@
+// Copyright (C) 2023 Blue Coral, Inc.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct AllowlistClaim {
+    /// The pubkey of the wallet that claimed its allowlist spot (32).
+    pub wallet: Pubkey,
+    /// The pubkey of the xNFT that the allowlist belongs to (32).
+    pub xnft: Pubkey,
+    /// The unix timestamp of when the allowlist spot was claimed (8).
+    pub claimed_ts: i64,
+    /// Bump nonce of the PDA (1).
+    pub bump: u8,
+    /// Unused reserved byte space for additive future changes.
+    pub _reserved: [u8; 32],
+}
+
+impl AllowlistClaim {
+    pub const LEN: usize = 8 + (32 * 2) + 8 + 1 + 32;
+
+    pub fn try_new(wallet: Pubkey, xnft: Pubkey, bump: u8) -> Result<Self> {
+        Ok(Self {
+            wallet,
+            xnft,
+            claimed_ts: Clock::get()?.unix_timestamp,
+            bump,
+            _reserved: [0; 32],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AllowlistClaim;
+
+    #[test]
+    fn account_size_matches() {
+        assert_eq!(AllowlistClaim::LEN, 113);
+    }
+}
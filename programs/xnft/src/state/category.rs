@@ -0,0 +1,104 @@
+// Copyright (C) 2023 Blue Coral, Inc.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use anchor_lang::prelude::*;
+
+use crate::{CustomError, MAX_CATEGORIES, MAX_CATEGORY_NAME_LENGTH};
+
+#[account]
+pub struct Category {
+    /// The registry id of the category and its bit in `Xnft::categories` (1).
+    pub id: u8,
+    /// The bump nonce for the category's PDA (1).
+    pub bump: u8,
+    /// The display name of the category (4 + MAX_CATEGORY_NAME_LENGTH).
+    pub name: String,
+    /// Unused reserved byte space for future additive changes.
+    pub _reserved: [u8; 32],
+}
+
+impl Category {
+    pub const LEN: usize = 8 + 1 + 1 + (4 + MAX_CATEGORY_NAME_LENGTH) + 32;
+
+    pub fn try_new(id: u8, name: String, bump: u8) -> Result<Self> {
+        require_gt!(MAX_CATEGORIES, id, CustomError::CategoryIdExceedsMax);
+
+        let mut category = Self {
+            id,
+            bump,
+            name: String::default(),
+            _reserved: [0; 32],
+        };
+        category.set_name(name)?;
+        Ok(category)
+    }
+
+    pub fn set_name(&mut self, name: String) -> Result<()> {
+        require!(
+            name.len() <= MAX_CATEGORY_NAME_LENGTH,
+            CustomError::NameExceedsMaxLength,
+        );
+        self.name = name;
+        Ok(())
+    }
+
+    /// The ids of the categories set in the bitset, in ascending order.
+    pub fn ids(categories: u64) -> impl Iterator<Item = u8> {
+        (0..MAX_CATEGORIES).filter(move |id| categories & (1 << id) != 0)
+    }
+
+    /// Verifies that the accounts are the registered `Category` accounts of
+    /// every id set in the bitset, given once each in ascending id order.
+    pub fn verify_registered(categories: u64, accounts: &[AccountInfo]) -> Result<()> {
+        require_eq!(
+            categories.count_ones() as usize,
+            accounts.len(),
+            CustomError::CategoryAccountMismatch,
+        );
+
+        for (id, info) in Self::ids(categories).zip(accounts) {
+            let category = Account::<Category>::try_from(info)?;
+            require_eq!(category.id, id, CustomError::CategoryAccountMismatch);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn account_size_matches() {
+        assert_eq!(Category::LEN, 78);
+    }
+
+    #[test]
+    fn bitset_ids() {
+        assert_eq!(Category::ids(0).count(), 0);
+        assert_eq!(
+            Category::ids(1 | 1 << 5 | 1 << 63).collect::<Vec<_>>(),
+            vec![0, 5, 63]
+        );
+    }
+
+    #[test]
+    fn category_validation() {
+        assert!(Category::try_new(MAX_CATEGORIES - 1, "Games".to_owned(), 0).is_ok());
+        assert!(Category::try_new(MAX_CATEGORIES, "Games".to_owned(), 0).is_err());
+        assert!(Category::try_new(0, "x".repeat(MAX_CATEGORY_NAME_LENGTH + 1), 0).is_err());
+    }
+}
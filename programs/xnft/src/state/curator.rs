@@ -0,0 +1,245 @@
+// Copyright (C) 2023 Blue Coral, Inc.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use mpl_token_metadata::state::MAX_URI_LENGTH;
+
+use crate::{CustomError, MAX_CURATOR_MEMBERS};
+
+#[account]
+pub struct Curator {
+    /// The pubkey of the account that manages the curator (32).
+    pub authority: Pubkey,
+    /// The unix timestamp of when the account was created (8).
+    pub created_ts: i64,
+    /// The unix timestamp of the last time the account was updated (8).
+    pub updated_ts: i64,
+    /// The bump nonce for the curator's PDA (1).
+    pub bump: u8,
+    /// The unique name of the curator used to seed its PDA (4 + 32).
+    pub name: String,
+    /// The URI of the off-chain JSON data that describes the curator (4 + mpl_token_metadata::state::MAX_URI_LENGTH).
+    pub uri: String,
+    /// The pubkeys of the accounts that can sign on behalf of the curator (4 + 32 * MAX_CURATOR_MEMBERS).
+    pub members: Vec<Pubkey>,
+    /// The number of xNFTs that the curator is assigned to, whether verified or not (8).
+    pub total_xnfts: u64,
+    /// Unused reserved byte space for future additive changes.
+    pub _reserved: [u8; 24],
+}
+
+impl Curator {
+    pub const LEN: usize = 8
+        + 32
+        + 8
+        + 8
+        + 1
+        + (4 + 32)
+        + (4 + MAX_URI_LENGTH)
+        + (4 + 32 * MAX_CURATOR_MEMBERS)
+        + 8
+        + 24;
+
+    pub fn try_new(
+        authority: Pubkey,
+        name: String,
+        uri: String,
+        members: Vec<Pubkey>,
+        bump: u8,
+    ) -> Result<Self> {
+        let now = Clock::get()?.unix_timestamp;
+        let mut curator = Self {
+            authority,
+            created_ts: now,
+            updated_ts: now,
+            bump,
+            name,
+            uri: String::default(),
+            members: Vec::default(),
+            total_xnfts: 0,
+            _reserved: [0; 24],
+        };
+        curator.set_uri(uri)?;
+        curator.set_members(members)?;
+        Ok(curator)
+    }
+
+    /// Validates that the optional account is the curator assigned to an xNFT.
+    pub fn verify_assigned<'a, 'info>(
+        pubkey: &Pubkey,
+        info: Option<&'a AccountInfo<'info>>,
+    ) -> Result<&'a AccountInfo<'info>> {
+        let info = info.ok_or(error!(CustomError::CuratorMismatch))?;
+        require_keys_eq!(*info.key, *pubkey, CustomError::CuratorMismatch);
+        Ok(info)
+    }
+
+    /// Records the assignment of an xNFT to the curator account.
+    pub fn assign(info: &AccountInfo<'_>) -> Result<()> {
+        Self::update_total_xnfts(info, |total| total + 1)
+    }
+
+    /// Removes the assignment of an xNFT from the curator account.
+    pub fn unassign(info: &AccountInfo<'_>) -> Result<()> {
+        Self::update_total_xnfts(info, |total| total.saturating_sub(1))
+    }
+
+    /// Wallets that were assigned as curators before `Curator` program accounts
+    /// existed, or curators that have since been deleted, have no count to update.
+    fn update_total_xnfts(info: &AccountInfo<'_>, update: impl FnOnce(u64) -> u64) -> Result<()> {
+        let is_curator = info.owner == &crate::ID
+            && info.try_borrow_data()?.get(..8) == Some(&Self::discriminator()[..]);
+
+        if is_curator {
+            let mut curator = Account::<Curator>::try_from(info)?;
+            curator.total_xnfts = update(curator.total_xnfts);
+            curator.exit(&crate::ID)?;
+        }
+        Ok(())
+    }
+
+    /// Whether the argued key is the authority or one of the members of the curator.
+    pub fn is_authorized(&self, key: &Pubkey) -> bool {
+        self.authority == *key || self.members.contains(key)
+    }
+
+    pub fn set_members(&mut self, members: Vec<Pubkey>) -> Result<()> {
+        require_gte!(
+            MAX_CURATOR_MEMBERS,
+            members.len(),
+            CustomError::CuratorMembersExceedMax,
+        );
+        self.members = members;
+        Ok(())
+    }
+
+    pub fn set_uri(&mut self, uri: String) -> Result<()> {
+        require!(
+            uri.len() <= MAX_URI_LENGTH,
+            CustomError::UriExceedsMaxLength
+        );
+        self.uri = uri;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn account_size_matches() {
+        assert_eq!(Curator::LEN, 653);
+    }
+
+    #[test]
+    fn authorized_signers() {
+        let authority = Pubkey::new_unique();
+        let member = Pubkey::new_unique();
+        let mut c = Curator {
+            authority,
+            created_ts: 0,
+            updated_ts: 0,
+            bump: 0,
+            name: "curator".to_owned(),
+            uri: String::default(),
+            members: Vec::default(),
+            total_xnfts: 0,
+            _reserved: [0; 24],
+        };
+
+        assert!(c.is_authorized(&authority));
+        assert!(!c.is_authorized(&member));
+
+        c.set_members(vec![member]).unwrap();
+        assert!(c.is_authorized(&member));
+
+        assert_eq!(
+            c.set_members(vec![member; MAX_CURATOR_MEMBERS + 1])
+                .unwrap_err(),
+            anchor_lang::error::Error::from(CustomError::CuratorMembersExceedMax),
+        );
+    }
+
+    #[test]
+    fn assignments_are_counted() {
+        let key = Pubkey::new_unique();
+        let curator = Curator {
+            authority: Pubkey::new_unique(),
+            created_ts: 0,
+            updated_ts: 0,
+            bump: 0,
+            name: "curator".to_owned(),
+            uri: String::default(),
+            members: Vec::default(),
+            total_xnfts: 0,
+            _reserved: [0; 24],
+        };
+
+        let mut lamports = 0;
+        let mut data = vec![0; Curator::LEN];
+        curator.try_serialize(&mut data.as_mut_slice()).unwrap();
+        let info = AccountInfo::new(
+            &key,
+            false,
+            true,
+            &mut lamports,
+            &mut data,
+            &crate::ID,
+            false,
+            0,
+        );
+        let total = || Account::<Curator>::try_from(&info).unwrap().total_xnfts;
+
+        Curator::assign(&info).unwrap();
+        Curator::assign(&info).unwrap();
+        assert_eq!(total(), 2);
+
+        Curator::unassign(&info).unwrap();
+        assert_eq!(total(), 1);
+
+        assert_eq!(
+            Curator::verify_assigned(&Pubkey::new_unique(), Some(&info)).unwrap_err(),
+            anchor_lang::error::Error::from(CustomError::CuratorMismatch),
+        );
+        assert!(Curator::verify_assigned(&key, None).is_err());
+        assert_eq!(
+            Curator::verify_assigned(&key, Some(&info)).unwrap().key,
+            &key
+        );
+    }
+
+    #[test]
+    fn legacy_wallet_assignments_are_ignored() {
+        let key = Pubkey::new_unique();
+        let mut lamports = 0;
+        let mut data = [];
+        let system = anchor_lang::system_program::ID;
+        let info = AccountInfo::new(
+            &key,
+            true,
+            false,
+            &mut lamports,
+            &mut data,
+            &system,
+            false,
+            0,
+        );
+
+        Curator::assign(&info).unwrap();
+        Curator::unassign(&info).unwrap();
+    }
+}
@@ -0,0 +1,123 @@
+// Copyright (C) 2023 Blue Coral, Inc.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use anchor_lang::prelude::*;
+
+use crate::{
+    CustomError, PERMISSION_CHANGE_PRICE, PERMISSION_MANAGE_ACCESS, PERMISSION_SUSPEND,
+    PERMISSION_UPDATE,
+};
+
+#[account]
+pub struct Delegate {
+    /// The pubkey of the xNFT that the delegate can manage (32).
+    pub xnft: Pubkey,
+    /// The pubkey of the wallet that was delegated to (32).
+    pub wallet: Pubkey,
+    /// The pubkey of the owner of the xNFT that granted the permissions (32).
+    pub owner: Pubkey,
+    /// The bitmask of `PERMISSION_*` flags granted to the wallet (1).
+    pub permissions: u8,
+    /// The unix timestamp of when the account was created (8).
+    pub created_ts: i64,
+    /// The unix timestamp of the last time the permissions were updated (8).
+    pub updated_ts: i64,
+    /// Bump nonce of the PDA (1).
+    pub bump: u8,
+    /// Unused reserved byte space for additive future changes.
+    pub _reserved: [u8; 32],
+}
+
+impl Delegate {
+    pub const LEN: usize = 8 + (32 * 3) + 1 + (8 * 2) + 1 + 32;
+
+    /// The union of all permission flags that can be granted to a delegate.
+    pub const ALL_PERMISSIONS: u8 =
+        PERMISSION_CHANGE_PRICE | PERMISSION_MANAGE_ACCESS | PERMISSION_SUSPEND | PERMISSION_UPDATE;
+
+    pub fn try_new(
+        xnft: Pubkey,
+        wallet: Pubkey,
+        owner: Pubkey,
+        permissions: u8,
+        bump: u8,
+    ) -> Result<Self> {
+        let now = Clock::get()?.unix_timestamp;
+        let mut delegate = Self {
+            xnft,
+            wallet,
+            owner,
+            permissions: 0,
+            created_ts: now,
+            updated_ts: now,
+            bump,
+            _reserved: [0; 32],
+        };
+        delegate.set_permissions(permissions)?;
+        Ok(delegate)
+    }
+
+    /// Whether the delegate has been granted all of the argued permission flags.
+    pub fn has_permissions(&self, permissions: u8) -> bool {
+        self.permissions & permissions == permissions
+    }
+
+    pub fn set_permissions(&mut self, permissions: u8) -> Result<()> {
+        require!(
+            permissions != 0 && permissions & !Self::ALL_PERMISSIONS == 0,
+            CustomError::InvalidDelegatePermissions,
+        );
+        self.permissions = permissions;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn account_size_matches() {
+        assert_eq!(Delegate::LEN, 154);
+    }
+
+    #[test]
+    fn permission_flags() {
+        let mut d = Delegate {
+            xnft: Pubkey::new_unique(),
+            wallet: Pubkey::new_unique(),
+            owner: Pubkey::new_unique(),
+            permissions: 0,
+            created_ts: 0,
+            updated_ts: 0,
+            bump: 0,
+            _reserved: [0; 32],
+        };
+
+        d.set_permissions(PERMISSION_SUSPEND | PERMISSION_UPDATE)
+            .unwrap();
+        assert!(d.has_permissions(PERMISSION_SUSPEND));
+        assert!(d.has_permissions(PERMISSION_SUSPEND | PERMISSION_UPDATE));
+        assert!(!d.has_permissions(PERMISSION_UPDATE | PERMISSION_CHANGE_PRICE));
+        assert!(!d.has_permissions(PERMISSION_MANAGE_ACCESS));
+
+        for invalid in [0, 1 << 4, Delegate::ALL_PERMISSIONS | 1 << 7] {
+            assert_eq!(
+                d.set_permissions(invalid).unwrap_err(),
+                anchor_lang::error::Error::from(CustomError::InvalidDelegatePermissions),
+            );
+        }
+    }
+}
@@ -0,0 +1,104 @@
+// Copyright (C) 2023 Blue Coral, Inc.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+use anchor_lang::prelude::*;
+
+use super::{Install, Xnft};
+use crate::CustomError;
+
+#[account]
+pub struct Escrow {
+    /// The pubkey of the installation that the payment was made for (32).
+    pub install: Pubkey,
+    /// The pubkey of the xNFT that was installed (32).
+    pub xnft: Pubkey,
+    /// The pubkey of the wallet that paid for the installation (32).
+    pub payer: Pubkey,
+    /// The optional SPL token mint that the payment is denominated in (33).
+    pub mint: Option<Pubkey>,
+    /// The amount of the install payment being held (8).
+    pub amount: u64,
+    /// The unix timestamp of when the installation can no longer be refunded (8).
+    pub refund_deadline: i64,
+    /// Bump nonce of the PDA (1).
+    pub bump: u8,
+    /// The edition of the installation, which seeds the PDA so that a
+    /// reinstallation does not collide with an unsettled escrow (8).
+    pub edition: u64,
+    /// Whether the payment is split across the creators when settled,
+    /// as set on the xNFT at the time of installation (1).
+    pub split_install_payments: bool,
+    /// The protocol fee taken from the payment when settled, as set in
+    /// the protocol config at the time of installation (2).
+    pub fee_basis_points: u16,
+    /// Unused reserved byte space for additive future changes.
+    pub _reserved: [u8; 21],
+}
+
+impl Escrow {
+    pub const LEN: usize = 8 + (32 * 3) + 33 + 8 + 8 + 1 + 8 + 1 + 2 + 21;
+
+    pub fn try_new(
+        install: &Account<'_, Install>,
+        xnft: &Account<'_, Xnft>,
+        payer: Pubkey,
+        fee_basis_points: u16,
+        bump: u8,
+    ) -> Result<Self> {
+        let window = xnft
+            .refund_window
+            .ok_or(error!(CustomError::InvalidRefundWindow))?;
+
+        Ok(Self {
+            install: install.key(),
+            xnft: xnft.key(),
+            payer,
+            mint: xnft.install_mint,
+            amount: xnft.install_price,
+            refund_deadline: Clock::get()?
+                .unix_timestamp
+                .checked_add(window)
+                .ok_or(error!(CustomError::InvalidRefundWindow))?,
+            bump,
+            edition: install.edition,
+            split_install_payments: xnft.split_install_payments,
+            fee_basis_points,
+            _reserved: [0; 21],
+        })
+    }
+
+    pub fn as_seeds(&self) -> [Vec<u8>; 4] {
+        [
+            "escrow".as_bytes().to_vec(),
+            self.install.to_bytes().to_vec(),
+            self.edition.to_le_bytes().to_vec(),
+            vec![self.bump],
+        ]
+    }
+
+    /// Whether the payment can still be reclaimed at the argued unix timestamp.
+    pub fn is_refundable(&self, now: i64) -> bool {
+        now <= self.refund_deadline
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Escrow;
+
+    #[test]
+    fn account_size_matches() {
+        assert_eq!(Escrow::LEN, 186);
+    }
+}
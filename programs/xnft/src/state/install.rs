@@ -16,6 +16,7 @@
 use anchor_lang::prelude::*;
 
 use super::Xnft;
+use crate::CustomError;
 
 #[account]
 pub struct Install {
@@ -27,23 +28,53 @@ pub struct Install {
     pub master_metadata: Pubkey,
     /// The sequential installation number of the xNFT (8).
     pub edition: u64,
+    /// The unix timestamp of when a subscription installation lapses (9).
+    pub expires_ts: Option<i64>,
+    /// The optional Token-2022 mint of the non-transferable receipt for the edition (33).
+    pub receipt_mint: Option<Pubkey>,
     /// Unused reserved byte space for additive future changes.
-    pub _reserved: [u8; 64],
+    pub _reserved: [u8; 22],
 }
 
 impl Install {
-    pub const LEN: usize = 8 + (32 * 3) + 8 + 64;
+    pub const LEN: usize = 8 + (32 * 3) + 8 + 9 + 33 + 22;
 
-    pub fn new(xnft: &mut Account<'_, Xnft>, owner: &Pubkey) -> Self {
-        let i = Self {
+    pub fn try_new(xnft: &mut Account<'_, Xnft>, owner: &Pubkey) -> Result<Self> {
+        let now = Clock::get()?.unix_timestamp;
+        let mut i = Self {
             authority: *owner,
             xnft: xnft.key(),
             master_metadata: xnft.master_metadata,
             edition: xnft.total_installs,
-            _reserved: [0; 64],
+            expires_ts: None,
+            receipt_mint: None,
+            _reserved: [0; 22],
         };
+
+        if let Some(period) = xnft.subscription_period {
+            i.renew(period, now)?;
+        }
+
         xnft.total_installs += 1;
-        i
+        Ok(i)
+    }
+
+    /// Whether the installation is usable at the argued unix timestamp, being
+    /// either a permanent installation or a subscription that has not lapsed.
+    pub fn is_active(&self, now: i64) -> bool {
+        self.expires_ts.map_or(true, |ts| now < ts)
+    }
+
+    /// Extends the expiry of the installation by a subscription period, starting
+    /// from the current expiry if still active or from now if it has lapsed.
+    pub fn renew(&mut self, period: i64, now: i64) -> Result<()> {
+        let start = self.expires_ts.map_or(now, |ts| ts.max(now));
+        self.expires_ts = Some(
+            start
+                .checked_add(period)
+                .ok_or(error!(CustomError::InvalidSubscriptionPeriod))?,
+        );
+        Ok(())
     }
 }
 
@@ -51,8 +82,41 @@ impl Install {
 mod tests {
     use super::Install;
 
+    fn default_install() -> Install {
+        Install {
+            authority: Default::default(),
+            xnft: Default::default(),
+            master_metadata: Default::default(),
+            edition: 0,
+            expires_ts: None,
+            receipt_mint: None,
+            _reserved: [0; 22],
+        }
+    }
+
     #[test]
     fn account_size_matches() {
         assert_eq!(Install::LEN, 176);
     }
+
+    #[test]
+    fn subscription_renewals() {
+        let mut i = default_install();
+        assert!(i.is_active(i64::MAX));
+
+        i.renew(100, 1_000).unwrap();
+        assert_eq!(i.expires_ts, Some(1_100));
+        assert!(i.is_active(1_099));
+        assert!(!i.is_active(1_100));
+
+        // Renewing early extends from the current expiry.
+        i.renew(100, 1_050).unwrap();
+        assert_eq!(i.expires_ts, Some(1_200));
+
+        // Renewing after lapsing extends from the time of renewal.
+        i.renew(100, 5_000).unwrap();
+        assert_eq!(i.expires_ts, Some(5_100));
+
+        assert!(i.renew(i64::MAX, 5_000).is_err());
+    }
 }
@@ -14,15 +14,37 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 mod access;
+mod allowlist_claim;
+mod category;
+mod curator;
+mod delegate;
+mod escrow;
 mod install;
 mod parameters;
+mod protocol_config;
+mod release;
 mod review;
+mod review_reply;
 #[cfg(any(test, feature = "cli"))]
 mod serialization;
+mod update_proposal;
+mod voucher_nonce;
 mod xnft;
 
 pub use self::xnft::*; // use `self::` prefix to remove crate vs module ambiguity during builds
 pub use access::*;
+pub use allowlist_claim::*;
+pub use category::*;
+pub use curator::*;
+pub use delegate::*;
+pub use escrow::*;
 pub use install::*;
 pub use parameters::*;
+pub use protocol_config::*;
+pub use release::*;
 pub use review::*;
+pub use review_reply::*;
+#[cfg(any(test, feature = "cli"))]
+pub use serialization::to_hex;
+pub use update_proposal::*;
+pub use voucher_nonce::*;
@@ -14,8 +14,10 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use anchor_lang::prelude::*;
+use mpl_token_metadata::state::{MAX_NAME_LENGTH, MAX_URI_LENGTH};
 
-use super::Tag;
+use super::Xnft;
+use crate::{PERMISSION_CHANGE_PRICE, PERMISSION_MANAGE_ACCESS, PERMISSION_UPDATE};
 
 #[derive(AnchorDeserialize, AnchorSerialize)]
 pub struct CreatorsParam {
@@ -25,25 +27,209 @@ pub struct CreatorsParam {
 
 #[derive(AnchorDeserialize, AnchorSerialize)]
 pub struct CreateXnftParams {
+    pub categories: u64,
+    pub content_hash: Option<[u8; 32]>,
     pub creators: Vec<CreatorsParam>,
     pub curator: Option<Pubkey>,
     pub install_authority: Option<Pubkey>,
+    pub install_mint: Option<Pubkey>,
     pub install_price: u64,
     pub install_vault: Pubkey,
+    pub mint_install_receipts: bool,
+    pub refund_window: Option<i64>,
     pub seller_fee_basis_points: u16,
+    pub split_install_payments: bool,
+    pub subscription_period: Option<i64>,
     pub supply: Option<u64>,
     pub symbol: String,
-    pub tag: Tag,
     pub uri: String,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct InstallVoucher {
+    pub expires_ts: i64,
+    pub nonce: u64,
+    pub wallet: Pubkey,
+    pub xnft: Pubkey,
+}
+
+impl InstallVoucher {
+    /// Prefixed to the signed message so that a voucher signature cannot be
+    /// mistaken for the signature of any other payload.
+    pub const DOMAIN: &'static [u8] = b"xnft:install_voucher:";
+
+    /// The message that the install authority signs off-chain to issue the voucher.
+    pub fn message(&self) -> Vec<u8> {
+        [Self::DOMAIN, &self.try_to_vec().unwrap()].concat()
+    }
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateCuratorParams {
+    pub authority: Option<Pubkey>,
+    pub members: Option<Vec<Pubkey>>,
+    pub uri: Option<String>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct UpdateParams {
+    pub categories: Option<u64>,
+    pub content_hash: Option<[u8; 32]>,
     pub install_authority: Option<Pubkey>,
+    pub install_mint: Option<Option<Pubkey>>,
     pub install_price: Option<u64>,
     pub install_vault: Option<Pubkey>,
+    pub mint_install_receipts: Option<bool>,
     pub name: Option<String>,
+    pub refund_window: Option<Option<i64>>,
+    pub split_install_payments: Option<bool>,
+    pub subscription_period: Option<Option<i64>>,
     pub supply: Option<u64>,
-    pub tag: Option<Tag>,
     pub uri: Option<String>,
 }
+
+impl UpdateParams {
+    pub const MAX_LEN: usize = 9
+        + 33
+        + 33
+        + 34
+        + 9
+        + 33
+        + 2
+        + (1 + 4 + MAX_NAME_LENGTH)
+        + 10
+        + 2
+        + 10
+        + 9
+        + (1 + 4 + MAX_URI_LENGTH);
+
+    /// The delegate permissions required to apply the updates to the xNFT, where
+    /// changing what installers pay or where and when the owner is paid requires
+    /// `PERMISSION_CHANGE_PRICE`, changing who may install requires
+    /// `PERMISSION_MANAGE_ACCESS` and any other change requires `PERMISSION_UPDATE`.
+    pub fn required_permissions(&self, xnft: &Xnft) -> u8 {
+        let mut permissions = 0;
+
+        if self.install_price.is_some_and(|p| p != xnft.install_price)
+            || self.install_mint.is_some_and(|m| m != xnft.install_mint)
+            || self.install_vault.is_some_and(|v| v != xnft.install_vault)
+            || self
+                .split_install_payments
+                .is_some_and(|s| s != xnft.split_install_payments)
+            || self.refund_window.is_some_and(|w| w != xnft.refund_window)
+            || self
+                .subscription_period
+                .is_some_and(|p| p != xnft.subscription_period)
+        {
+            permissions |= PERMISSION_CHANGE_PRICE;
+        }
+
+        if self.install_authority != xnft.install_authority {
+            permissions |= PERMISSION_MANAGE_ACCESS;
+        }
+
+        if self.categories.is_some()
+            || self.content_hash.is_some()
+            || self.name.is_some()
+            || self.uri.is_some()
+            || self
+                .mint_install_receipts
+                .is_some_and(|m| m != xnft.mint_install_receipts)
+            || self.supply != xnft.supply
+        {
+            permissions |= PERMISSION_UPDATE;
+        }
+
+        permissions
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateProtocolConfigParams {
+    pub fee_basis_points: Option<u16>,
+    pub treasury: Option<Pubkey>,
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::Pubkey;
+
+    use super::UpdateParams;
+    use crate::state::xnft::tests::default_xnft;
+    use crate::state::Xnft;
+    use crate::{PERMISSION_CHANGE_PRICE, PERMISSION_MANAGE_ACCESS, PERMISSION_UPDATE};
+
+    fn no_updates(xnft: &Xnft) -> UpdateParams {
+        UpdateParams {
+            categories: None,
+            content_hash: None,
+            install_authority: xnft.install_authority,
+            install_mint: None,
+            install_price: None,
+            install_vault: None,
+            mint_install_receipts: None,
+            name: None,
+            refund_window: None,
+            split_install_payments: None,
+            subscription_period: None,
+            supply: xnft.supply,
+            uri: None,
+        }
+    }
+
+    #[test]
+    fn unchanged_fields_require_no_permissions() {
+        let x = default_xnft();
+        let updates = UpdateParams {
+            install_price: Some(x.install_price),
+            refund_window: Some(x.refund_window),
+            split_install_payments: Some(x.split_install_payments),
+            ..no_updates(&x)
+        };
+        assert_eq!(updates.required_permissions(&x), 0);
+    }
+
+    #[test]
+    fn split_install_payments_requires_change_price() {
+        let x = default_xnft();
+        let updates = UpdateParams {
+            split_install_payments: Some(!x.split_install_payments),
+            ..no_updates(&x)
+        };
+        assert_eq!(updates.required_permissions(&x), PERMISSION_CHANGE_PRICE);
+    }
+
+    #[test]
+    fn refund_window_requires_change_price() {
+        let x = default_xnft();
+        let updates = UpdateParams {
+            refund_window: Some(Some(60)),
+            ..no_updates(&x)
+        };
+        assert_eq!(updates.required_permissions(&x), PERMISSION_CHANGE_PRICE);
+    }
+
+    #[test]
+    fn install_authority_requires_manage_access() {
+        let x = default_xnft();
+        let updates = UpdateParams {
+            install_authority: Some(Pubkey::new_unique()),
+            ..no_updates(&x)
+        };
+        assert_eq!(updates.required_permissions(&x), PERMISSION_MANAGE_ACCESS);
+    }
+
+    #[test]
+    fn metadata_changes_require_update() {
+        let x = default_xnft();
+        let updates = UpdateParams {
+            name: Some("renamed".to_owned()),
+            install_price: Some(x.install_price + 1),
+            ..no_updates(&x)
+        };
+        assert_eq!(
+            updates.required_permissions(&x),
+            PERMISSION_UPDATE | PERMISSION_CHANGE_PRICE,
+        );
+    }
+}
@@ -0,0 +1,86 @@
+// Copyright (C) 2023 Blue Coral, Inc.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use anchor_lang::prelude::*;
+
+use crate::MAX_FEE_BASIS_POINTS;
+
+#[account]
+pub struct ProtocolConfig {
+    /// The pubkey of the wallet with administrative control of the protocol (32).
+    pub admin: Pubkey,
+    /// The optional pubkey of the proposed admin that must accept the role (33).
+    pub pending_admin: Option<Pubkey>,
+    /// The pubkey of the account that receives protocol fees (32).
+    pub treasury: Pubkey,
+    /// The protocol fee taken from install payments and donations in basis points (2).
+    pub fee_basis_points: u16,
+    /// Bump nonce of the PDA (1).
+    pub bump: u8,
+    /// Whether the protocol is paused for all installs, reviews and updates (1).
+    pub paused: bool,
+    /// Unused reserved byte space for additive future changes.
+    pub _reserved: [u8; 32],
+}
+
+impl ProtocolConfig {
+    pub const LEN: usize = 8 + 32 + 33 + 32 + 2 + 1 + 1 + 32;
+
+    pub fn new(admin: Pubkey, treasury: Pubkey, fee_basis_points: u16, bump: u8) -> Self {
+        Self {
+            admin,
+            pending_admin: None,
+            treasury,
+            fee_basis_points,
+            bump,
+            paused: false,
+            _reserved: [0; 32],
+        }
+    }
+
+    /// Calculates the portion of the argued amount owed to the protocol treasury.
+    pub fn fee_for(&self, amount: u64) -> u64 {
+        Self::fee(amount, self.fee_basis_points)
+    }
+
+    /// Calculates the portion of the argued amount owed to the protocol treasury
+    /// at a fee that may since have been changed, such as that of an escrow.
+    pub fn fee(amount: u64, fee_basis_points: u16) -> u64 {
+        (amount as u128 * fee_basis_points as u128 / MAX_FEE_BASIS_POINTS as u128) as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ProtocolConfig;
+
+    #[test]
+    fn account_size_matches() {
+        assert_eq!(ProtocolConfig::LEN, 141);
+    }
+
+    #[test]
+    fn fee_calculation() {
+        let mut c = ProtocolConfig::new(Default::default(), Default::default(), 0, 0);
+        assert_eq!(c.fee_for(1_000), 0);
+
+        c.fee_basis_points = 250;
+        assert_eq!(c.fee_for(1_000), 25);
+        assert_eq!(c.fee_for(39), 0);
+
+        c.fee_basis_points = 10_000;
+        assert_eq!(c.fee_for(u64::MAX), u64::MAX);
+    }
+}
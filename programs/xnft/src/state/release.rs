@@ -0,0 +1,76 @@
+// Copyright (C) 2023 Blue Coral, Inc.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use anchor_lang::prelude::*;
+use mpl_token_metadata::state::MAX_URI_LENGTH;
+
+use super::Xnft;
+
+#[account]
+pub struct Release {
+    /// The pubkey of the associated xNFT (32).
+    pub xnft: Pubkey,
+    /// The sequential version number of the release, starting from 1 (4).
+    pub version: u32,
+    /// The pubkey of the account that signed for the release to be published (32).
+    pub publisher: Pubkey,
+    /// The unix timestamp of when the release was published (8).
+    pub created_ts: i64,
+    /// The optional SHA-256 digest of the bundle content at the URI (33).
+    pub content_hash: Option<[u8; 32]>,
+    /// The bump nonce for the release's PDA (1).
+    pub bump: u8,
+    /// The URI of the xNFT bundle for this release (4 + mpl_token_metadata::state::MAX_URI_LENGTH).
+    pub uri: String,
+    /// Unused reserved byte space for future additive changes.
+    pub _reserved: [u8; 32],
+}
+
+impl Release {
+    pub const LEN: usize = 8 + 32 + 4 + 32 + 8 + 33 + 1 + (4 + MAX_URI_LENGTH) + 32;
+
+    /// Records the current URI of the xNFT as its next release version,
+    /// which also becomes the current release of the xNFT.
+    pub fn try_new(
+        xnft: &mut Account<'_, Xnft>,
+        publisher: Pubkey,
+        content_hash: Option<[u8; 32]>,
+        bump: u8,
+    ) -> Result<Self> {
+        xnft.total_releases += 1;
+        xnft.current_release = xnft.total_releases;
+
+        Ok(Self {
+            xnft: xnft.key(),
+            version: xnft.total_releases,
+            publisher,
+            created_ts: Clock::get()?.unix_timestamp,
+            content_hash,
+            bump,
+            uri: xnft.uri.clone(),
+            _reserved: [0; 32],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Release;
+
+    #[test]
+    fn account_size_matches() {
+        assert_eq!(Release::LEN, 354);
+    }
+}
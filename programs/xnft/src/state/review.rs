@@ -27,25 +27,27 @@ pub struct Review {
     pub rating: u8,
     /// The URI of the off-chain JSON data that holds the comment (4 + len).
     pub uri: String,
+    /// Whether the review was hidden by the verified curator of the xNFT (1).
+    pub hidden: bool,
     /// Unused reserved byte space for future additive changes.
-    pub _reserved: [u8; 32],
+    pub _reserved: [u8; 31],
 }
 
 impl Review {
-    pub fn len(uri: String) -> usize {
-        8 + 32 + 32 + 1 + (4 + uri.len()) + 32
+    pub fn len(uri: &str) -> usize {
+        8 + 32 + 32 + 1 + (4 + uri.len()) + 1 + 31
     }
 
     pub fn new(xnft: &mut Account<'_, Xnft>, author: &Pubkey, uri: String, rating: u8) -> Self {
-        xnft.total_rating += std::convert::TryInto::<u64>::try_into(rating).unwrap();
-        xnft.num_ratings += 1;
+        xnft.add_rating(rating);
 
         Self {
             author: *author,
             xnft: xnft.key(),
             rating,
             uri,
-            _reserved: [0; 32],
+            hidden: false,
+            _reserved: [0; 31],
         }
     }
 }
@@ -56,6 +58,6 @@ mod tests {
 
     #[test]
     fn account_size_matches() {
-        assert_eq!(Review::len("tests".into()), 114);
+        assert_eq!(Review::len("tests"), 114);
     }
 }
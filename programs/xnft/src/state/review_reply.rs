@@ -0,0 +1,89 @@
+// Copyright (C) 2023 Blue Coral, Inc.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+use anchor_lang::prelude::*;
+
+use crate::CustomError;
+
+#[account]
+pub struct ReviewReply {
+    /// The pubkey of the review being replied to (32).
+    pub review: Pubkey,
+    /// The pubkey of the associated xNFT (32).
+    pub xnft: Pubkey,
+    /// The pubkey of the master token holder that wrote the reply (32).
+    pub author: Pubkey,
+    /// The unix timestamp of when the reply was created (8).
+    pub created_ts: i64,
+    /// The unix timestamp of the last time the reply was updated (8).
+    pub updated_ts: i64,
+    /// Bump nonce of the PDA (1).
+    pub bump: u8,
+    /// The URI of the off-chain JSON data that holds the reply (4 + len).
+    pub uri: String,
+    /// Unused reserved byte space for future additive changes.
+    pub _reserved: [u8; 32],
+}
+
+impl ReviewReply {
+    pub fn len(uri: &str) -> usize {
+        8 + (32 * 3) + 8 + 8 + 1 + (4 + uri.len()) + 32
+    }
+
+    pub fn try_new(
+        review: Pubkey,
+        xnft: Pubkey,
+        author: Pubkey,
+        uri: String,
+        bump: u8,
+    ) -> Result<Self> {
+        let now = Clock::get()?.unix_timestamp;
+        Ok(Self {
+            review,
+            xnft,
+            author,
+            created_ts: now,
+            updated_ts: now,
+            bump,
+            uri,
+            _reserved: [0; 32],
+        })
+    }
+
+    /// Closes the reply, if any, to a review that is being closed so that a later
+    /// review by the same author does not inherit it, refunding its rent to the
+    /// author of the reply.
+    pub fn close_with_review<'info>(
+        reply: Option<&Account<'info, Self>>,
+        author: Option<&UncheckedAccount<'info>>,
+    ) -> Result<()> {
+        if let Some(reply) = reply {
+            let author = author
+                .filter(|a| a.key() == reply.author)
+                .ok_or(error!(CustomError::ReplyAuthorMismatch))?;
+            reply.close(author.to_account_info())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ReviewReply;
+
+    #[test]
+    fn account_size_matches() {
+        assert_eq!(ReviewReply::len("tests"), 162);
+    }
+}
@@ -13,19 +13,43 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use serde::ser::{Serialize, SerializeStruct, Serializer};
+use std::fmt::Write;
 
-use super::{Access, CuratorStatus, Install, Kind, Review, Tag, Xnft};
+use serde::ser::{Serialize, SerializeStruct, SerializeStructVariant, Serializer};
+
+use super::{
+    Access, AllowlistClaim, Category, Curator, CuratorStatus, Delegate, Escrow, Install,
+    InstallGate, Kind, ProposalStatus, ProtocolConfig, Release, Review, ReviewReply, Suspension,
+    SuspensionRole, UpdateParams, UpdateProposal, VoucherNonce, Xnft,
+};
+
+/// Lists the ids set in a category bitset.
+fn category_ids(categories: u64) -> Vec<u8> {
+    Category::ids(categories).collect()
+}
+
+/// Encodes a digest, such as a content hash or merkle root, as a lowercase hex string.
+pub fn to_hex(bytes: impl AsRef<[u8]>) -> String {
+    let bytes = bytes.as_ref();
+    let mut hex = String::with_capacity(bytes.len() * 2);
+    for b in bytes {
+        write!(hex, "{b:02x}").unwrap();
+    }
+    hex
+}
 
 impl Serialize for Access {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        let mut s = serializer.serialize_struct("Access", 3)?;
+        let mut s = serializer.serialize_struct("Access", 6)?;
         s.serialize_field("wallet", &self.wallet.to_string())?;
         s.serialize_field("xnft", &self.xnft.to_string())?;
         s.serialize_field("bump", &self.bump)?;
+        s.serialize_field("expiresTimestamp", &self.expires_ts)?;
+        s.serialize_field("maxInstalls", &self.max_installs)?;
+        s.serialize_field("totalInstalls", &self.total_installs)?;
         s.end()
     }
 }
@@ -36,6 +60,163 @@ impl std::fmt::Debug for Access {
             .field("wallet", &self.wallet)
             .field("xnft", &self.xnft)
             .field("bump", &self.bump)
+            .field("expires_ts", &self.expires_ts)
+            .field("max_installs", &self.max_installs)
+            .field("total_installs", &self.total_installs)
+            .finish()
+    }
+}
+
+impl Serialize for AllowlistClaim {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut s = serializer.serialize_struct("AllowlistClaim", 4)?;
+        s.serialize_field("wallet", &self.wallet.to_string())?;
+        s.serialize_field("xnft", &self.xnft.to_string())?;
+        s.serialize_field("claimedTimestamp", &self.claimed_ts)?;
+        s.serialize_field("bump", &self.bump)?;
+        s.end()
+    }
+}
+
+impl std::fmt::Debug for AllowlistClaim {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AllowlistClaim")
+            .field("wallet", &self.wallet)
+            .field("xnft", &self.xnft)
+            .field("claimed_ts", &self.claimed_ts)
+            .field("bump", &self.bump)
+            .finish()
+    }
+}
+
+impl Serialize for Category {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut s = serializer.serialize_struct("Category", 3)?;
+        s.serialize_field("id", &self.id)?;
+        s.serialize_field("bump", &self.bump)?;
+        s.serialize_field("name", &self.name)?;
+        s.end()
+    }
+}
+
+impl std::fmt::Debug for Category {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Category")
+            .field("id", &self.id)
+            .field("bump", &self.bump)
+            .field("name", &self.name)
+            .finish()
+    }
+}
+
+impl Serialize for Curator {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut s = serializer.serialize_struct("Curator", 8)?;
+        s.serialize_field("authority", &self.authority.to_string())?;
+        s.serialize_field("createdTimestamp", &self.created_ts)?;
+        s.serialize_field("updatedTimestamp", &self.updated_ts)?;
+        s.serialize_field("bump", &self.bump)?;
+        s.serialize_field("name", &self.name)?;
+        s.serialize_field("uri", &self.uri)?;
+        s.serialize_field(
+            "members",
+            &self
+                .members
+                .iter()
+                .map(|pk| pk.to_string())
+                .collect::<Vec<_>>(),
+        )?;
+        s.serialize_field("totalXnfts", &self.total_xnfts)?;
+        s.end()
+    }
+}
+
+impl std::fmt::Debug for Curator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Curator")
+            .field("authority", &self.authority)
+            .field("created_ts", &self.created_ts)
+            .field("updated_ts", &self.updated_ts)
+            .field("bump", &self.bump)
+            .field("name", &self.name)
+            .field("uri", &self.uri)
+            .field("members", &self.members)
+            .field("total_xnfts", &self.total_xnfts)
+            .finish()
+    }
+}
+
+impl Serialize for Delegate {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut s = serializer.serialize_struct("Delegate", 7)?;
+        s.serialize_field("xnft", &self.xnft.to_string())?;
+        s.serialize_field("wallet", &self.wallet.to_string())?;
+        s.serialize_field("owner", &self.owner.to_string())?;
+        s.serialize_field("permissions", &self.permissions)?;
+        s.serialize_field("createdTimestamp", &self.created_ts)?;
+        s.serialize_field("updatedTimestamp", &self.updated_ts)?;
+        s.serialize_field("bump", &self.bump)?;
+        s.end()
+    }
+}
+
+impl std::fmt::Debug for Delegate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Delegate")
+            .field("xnft", &self.xnft)
+            .field("wallet", &self.wallet)
+            .field("owner", &self.owner)
+            .field("permissions", &format_args!("{:#06b}", self.permissions))
+            .field("created_ts", &self.created_ts)
+            .field("updated_ts", &self.updated_ts)
+            .field("bump", &self.bump)
+            .finish()
+    }
+}
+
+impl Serialize for Escrow {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut s = serializer.serialize_struct("Escrow", 9)?;
+        s.serialize_field("install", &self.install.to_string())?;
+        s.serialize_field("xnft", &self.xnft.to_string())?;
+        s.serialize_field("payer", &self.payer.to_string())?;
+        s.serialize_field("mint", &self.mint.map(|pk| pk.to_string()))?;
+        s.serialize_field("amount", &self.amount)?;
+        s.serialize_field("refundDeadline", &self.refund_deadline)?;
+        s.serialize_field("edition", &self.edition)?;
+        s.serialize_field("splitInstallPayments", &self.split_install_payments)?;
+        s.serialize_field("feeBasisPoints", &self.fee_basis_points)?;
+        s.end()
+    }
+}
+
+impl std::fmt::Debug for Escrow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Escrow")
+            .field("install", &self.install)
+            .field("xnft", &self.xnft)
+            .field("payer", &self.payer)
+            .field("mint", &self.mint)
+            .field("amount", &self.amount)
+            .field("refund_deadline", &self.refund_deadline)
+            .field("edition", &self.edition)
+            .field("split_install_payments", &self.split_install_payments)
+            .field("fee_basis_points", &self.fee_basis_points)
             .finish()
     }
 }
@@ -45,11 +226,13 @@ impl Serialize for Install {
     where
         S: Serializer,
     {
-        let mut s = serializer.serialize_struct("Install", 4)?;
+        let mut s = serializer.serialize_struct("Install", 6)?;
         s.serialize_field("authority", &self.authority.to_string())?;
         s.serialize_field("xnft", &self.xnft.to_string())?;
         s.serialize_field("masterMetadata", &self.master_metadata.to_string())?;
         s.serialize_field("edition", &self.edition)?;
+        s.serialize_field("expiresTs", &self.expires_ts)?;
+        s.serialize_field("receiptMint", &self.receipt_mint.map(|pk| pk.to_string()))?;
         s.end()
     }
 }
@@ -61,6 +244,64 @@ impl std::fmt::Debug for Install {
             .field("xnft", &self.xnft)
             .field("master_metadata", &self.master_metadata)
             .field("edition", &self.edition)
+            .field("expires_ts", &self.expires_ts)
+            .field("receipt_mint", &self.receipt_mint)
+            .finish()
+    }
+}
+
+impl Serialize for ProtocolConfig {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut s = serializer.serialize_struct("ProtocolConfig", 5)?;
+        s.serialize_field("admin", &self.admin.to_string())?;
+        s.serialize_field("pendingAdmin", &self.pending_admin.map(|pk| pk.to_string()))?;
+        s.serialize_field("treasury", &self.treasury.to_string())?;
+        s.serialize_field("feeBasisPoints", &self.fee_basis_points)?;
+        s.serialize_field("paused", &self.paused)?;
+        s.end()
+    }
+}
+
+impl std::fmt::Debug for ProtocolConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ProtocolConfig")
+            .field("admin", &self.admin)
+            .field("pending_admin", &self.pending_admin)
+            .field("treasury", &self.treasury)
+            .field("fee_basis_points", &self.fee_basis_points)
+            .field("paused", &self.paused)
+            .finish()
+    }
+}
+
+impl Serialize for Release {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut s = serializer.serialize_struct("Release", 6)?;
+        s.serialize_field("xnft", &self.xnft.to_string())?;
+        s.serialize_field("version", &self.version)?;
+        s.serialize_field("publisher", &self.publisher.to_string())?;
+        s.serialize_field("createdTimestamp", &self.created_ts)?;
+        s.serialize_field("contentHash", &self.content_hash.as_ref().map(to_hex))?;
+        s.serialize_field("uri", &self.uri)?;
+        s.end()
+    }
+}
+
+impl std::fmt::Debug for Release {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Release")
+            .field("xnft", &self.xnft)
+            .field("version", &self.version)
+            .field("publisher", &self.publisher)
+            .field("created_ts", &self.created_ts)
+            .field("content_hash", &self.content_hash.as_ref().map(to_hex))
+            .field("uri", &self.uri)
             .finish()
     }
 }
@@ -70,11 +311,12 @@ impl Serialize for Review {
     where
         S: Serializer,
     {
-        let mut s = serializer.serialize_struct("Review", 4)?;
+        let mut s = serializer.serialize_struct("Review", 5)?;
         s.serialize_field("author", &self.author.to_string())?;
         s.serialize_field("xnft", &self.xnft.to_string())?;
         s.serialize_field("rating", &self.rating)?;
         s.serialize_field("uri", &self.uri)?;
+        s.serialize_field("hidden", &self.hidden)?;
         s.end()
     }
 }
@@ -86,6 +328,94 @@ impl std::fmt::Debug for Review {
             .field("xnft", &self.xnft)
             .field("rating", &self.rating)
             .field("uri", &self.uri)
+            .field("hidden", &self.hidden)
+            .finish()
+    }
+}
+
+impl Serialize for ReviewReply {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut s = serializer.serialize_struct("ReviewReply", 6)?;
+        s.serialize_field("review", &self.review.to_string())?;
+        s.serialize_field("xnft", &self.xnft.to_string())?;
+        s.serialize_field("author", &self.author.to_string())?;
+        s.serialize_field("createdTimestamp", &self.created_ts)?;
+        s.serialize_field("updatedTimestamp", &self.updated_ts)?;
+        s.serialize_field("uri", &self.uri)?;
+        s.end()
+    }
+}
+
+impl std::fmt::Debug for ReviewReply {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ReviewReply")
+            .field("review", &self.review)
+            .field("xnft", &self.xnft)
+            .field("author", &self.author)
+            .field("created_ts", &self.created_ts)
+            .field("updated_ts", &self.updated_ts)
+            .field("uri", &self.uri)
+            .finish()
+    }
+}
+
+impl Serialize for UpdateProposal {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut s = serializer.serialize_struct("UpdateProposal", 7)?;
+        s.serialize_field("xnft", &self.xnft.to_string())?;
+        s.serialize_field("proposer", &self.proposer.to_string())?;
+        s.serialize_field("curator", &self.curator.to_string())?;
+        s.serialize_field("status", &self.status)?;
+        s.serialize_field("createdTimestamp", &self.created_ts)?;
+        s.serialize_field("reviewedTimestamp", &self.reviewed_ts)?;
+        s.serialize_field("updates", &self.updates)?;
+        s.end()
+    }
+}
+
+impl std::fmt::Debug for UpdateProposal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UpdateProposal")
+            .field("xnft", &self.xnft)
+            .field("proposer", &self.proposer)
+            .field("curator", &self.curator)
+            .field("status", &self.status)
+            .field("created_ts", &self.created_ts)
+            .field("reviewed_ts", &self.reviewed_ts)
+            .field("updates", &self.updates)
+            .finish()
+    }
+}
+
+impl Serialize for VoucherNonce {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut s = serializer.serialize_struct("VoucherNonce", 5)?;
+        s.serialize_field("xnft", &self.xnft.to_string())?;
+        s.serialize_field("wallet", &self.wallet.to_string())?;
+        s.serialize_field("nonce", &self.nonce)?;
+        s.serialize_field("redeemedTimestamp", &self.redeemed_ts)?;
+        s.serialize_field("bump", &self.bump)?;
+        s.end()
+    }
+}
+
+impl std::fmt::Debug for VoucherNonce {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("VoucherNonce")
+            .field("xnft", &self.xnft)
+            .field("wallet", &self.wallet)
+            .field("nonce", &self.nonce)
+            .field("redeemed_ts", &self.redeemed_ts)
+            .field("bump", &self.bump)
             .finish()
     }
 }
@@ -95,7 +425,7 @@ impl Serialize for Xnft {
     where
         S: Serializer,
     {
-        let mut s = serializer.serialize_struct("Xnft", 19)?;
+        let mut s = serializer.serialize_struct("Xnft", 31)?;
         s.serialize_field("publisher", &self.publisher.to_string())?;
         s.serialize_field("installVault", &self.install_vault.to_string())?;
         s.serialize_field("masterMetadata", &self.master_metadata.to_string())?;
@@ -108,7 +438,6 @@ impl Serialize for Xnft {
         s.serialize_field("uri", &self.uri)?;
         s.serialize_field("mintSeedName", &self.mint_seed_name)?;
         s.serialize_field("kind", &self.kind)?;
-        s.serialize_field("tag", &self.tag)?;
         s.serialize_field("supply", &self.supply)?;
         s.serialize_field("totalInstalls", &self.total_installs)?;
         s.serialize_field("installPrice", &self.install_price)?;
@@ -118,6 +447,19 @@ impl Serialize for Xnft {
         s.serialize_field("numberOfRatings", &self.num_ratings)?;
         s.serialize_field("suspended", &self.suspended)?;
         s.serialize_field("bump", &self.bump[0])?;
+        s.serialize_field("installMint", &self.install_mint.map(|pk| pk.to_string()))?;
+        s.serialize_field("splitInstallPayments", &self.split_install_payments)?;
+        s.serialize_field("subscriptionPeriod", &self.subscription_period)?;
+        s.serialize_field("refundWindow", &self.refund_window)?;
+        s.serialize_field("mintInstallReceipts", &self.mint_install_receipts)?;
+        s.serialize_field("ratingCounts", &self.rating_counts)?;
+        s.serialize_field("currentRelease", &self.current_release)?;
+        s.serialize_field("totalReleases", &self.total_releases)?;
+        s.serialize_field("contentHash", &self.content_hash.as_ref().map(to_hex))?;
+        s.serialize_field("allowlistRoot", &self.allowlist_root.as_ref().map(to_hex))?;
+        s.serialize_field("installGate", &self.install_gate)?;
+        s.serialize_field("suspension", &self.suspension)?;
+        s.serialize_field("categories", &category_ids(self.categories))?;
         s.end()
     }
 }
@@ -134,7 +476,6 @@ impl std::fmt::Debug for Xnft {
             .field("uri", &self.uri)
             .field("mint_seed_name", &self.mint_seed_name)
             .field("kind", &self.kind)
-            .field("tag", &self.tag)
             .field("supply", &self.supply)
             .field("total_installs", &self.total_installs)
             .field("install_price", &self.install_price)
@@ -144,6 +485,19 @@ impl std::fmt::Debug for Xnft {
             .field("num_ratings", &self.num_ratings)
             .field("suspended", &self.suspended)
             .field("bump", &self.bump)
+            .field("install_mint", &self.install_mint)
+            .field("split_install_payments", &self.split_install_payments)
+            .field("subscription_period", &self.subscription_period)
+            .field("refund_window", &self.refund_window)
+            .field("mint_install_receipts", &self.mint_install_receipts)
+            .field("rating_counts", &self.rating_counts)
+            .field("current_release", &self.current_release)
+            .field("total_releases", &self.total_releases)
+            .field("content_hash", &self.content_hash.as_ref().map(to_hex))
+            .field("allowlist_root", &self.allowlist_root.as_ref().map(to_hex))
+            .field("install_gate", &self.install_gate)
+            .field("suspension", &self.suspension)
+            .field("categories", &category_ids(self.categories))
             .finish()
     }
 }
@@ -160,16 +514,62 @@ impl Serialize for Kind {
     }
 }
 
-impl Serialize for Tag {
+impl Serialize for InstallGate {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            InstallGate::Collection { mint } => {
+                let mut s =
+                    serializer.serialize_struct_variant("InstallGate", 0, "Collection", 1)?;
+                s.serialize_field("mint", &mint.to_string())?;
+                s.end()
+            }
+            InstallGate::Token { mint, amount } => {
+                let mut s = serializer.serialize_struct_variant("InstallGate", 1, "Token", 2)?;
+                s.serialize_field("mint", &mint.to_string())?;
+                s.serialize_field("amount", amount)?;
+                s.end()
+            }
+        }
+    }
+}
+
+impl Serialize for ProposalStatus {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
         match *self {
-            Tag::None => serializer.serialize_unit_variant("Tag", 0, "None"),
-            Tag::Defi => serializer.serialize_unit_variant("Tag", 1, "Defi"),
-            Tag::Game => serializer.serialize_unit_variant("Tag", 2, "Game"),
-            Tag::Nfts => serializer.serialize_unit_variant("Tag", 3, "Nfts"),
+            ProposalStatus::Pending => {
+                serializer.serialize_unit_variant("ProposalStatus", 0, "Pending")
+            }
+            ProposalStatus::Approved => {
+                serializer.serialize_unit_variant("ProposalStatus", 1, "Approved")
+            }
+            ProposalStatus::Rejected => {
+                serializer.serialize_unit_variant("ProposalStatus", 2, "Rejected")
+            }
+        }
+    }
+}
+
+impl Serialize for SuspensionRole {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match *self {
+            SuspensionRole::Owner => {
+                serializer.serialize_unit_variant("SuspensionRole", 0, "Owner")
+            }
+            SuspensionRole::Curator => {
+                serializer.serialize_unit_variant("SuspensionRole", 1, "Curator")
+            }
+            SuspensionRole::Admin => {
+                serializer.serialize_unit_variant("SuspensionRole", 2, "Admin")
+            }
         }
     }
 }
@@ -195,6 +595,80 @@ impl std::fmt::Debug for CuratorStatus {
     }
 }
 
+impl Serialize for Suspension {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut s = serializer.serialize_struct("Suspension", 4)?;
+        s.serialize_field("authority", &self.authority.to_string())?;
+        s.serialize_field("role", &self.role)?;
+        s.serialize_field("reason", &self.reason)?;
+        s.serialize_field("suspendedTimestamp", &self.suspended_ts)?;
+        s.end()
+    }
+}
+
+impl std::fmt::Debug for Suspension {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Suspension")
+            .field("authority", &self.authority)
+            .field("role", &self.role)
+            .field("reason", &self.reason)
+            .field("suspended_ts", &self.suspended_ts)
+            .finish()
+    }
+}
+
+impl Serialize for UpdateParams {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut s = serializer.serialize_struct("UpdateParams", 13)?;
+        s.serialize_field("categories", &self.categories.map(category_ids))?;
+        s.serialize_field("contentHash", &self.content_hash.as_ref().map(to_hex))?;
+        s.serialize_field(
+            "installAuthority",
+            &self.install_authority.map(|pk| pk.to_string()),
+        )?;
+        s.serialize_field(
+            "installMint",
+            &self.install_mint.map(|m| m.map(|pk| pk.to_string())),
+        )?;
+        s.serialize_field("installPrice", &self.install_price)?;
+        s.serialize_field("installVault", &self.install_vault.map(|pk| pk.to_string()))?;
+        s.serialize_field("mintInstallReceipts", &self.mint_install_receipts)?;
+        s.serialize_field("name", &self.name)?;
+        s.serialize_field("refundWindow", &self.refund_window)?;
+        s.serialize_field("splitInstallPayments", &self.split_install_payments)?;
+        s.serialize_field("subscriptionPeriod", &self.subscription_period)?;
+        s.serialize_field("supply", &self.supply)?;
+        s.serialize_field("uri", &self.uri)?;
+        s.end()
+    }
+}
+
+impl std::fmt::Debug for UpdateParams {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UpdateParams")
+            .field("categories", &self.categories.map(category_ids))
+            .field("content_hash", &self.content_hash)
+            .field("install_authority", &self.install_authority)
+            .field("install_mint", &self.install_mint)
+            .field("install_price", &self.install_price)
+            .field("install_vault", &self.install_vault)
+            .field("mint_install_receipts", &self.mint_install_receipts)
+            .field("name", &self.name)
+            .field("refund_window", &self.refund_window)
+            .field("split_install_payments", &self.split_install_payments)
+            .field("subscription_period", &self.subscription_period)
+            .field("supply", &self.supply)
+            .field("uri", &self.uri)
+            .finish()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -206,17 +680,101 @@ mod tests {
             wallet: Default::default(),
             xnft: Default::default(),
             bump: 0,
+            expires_ts: None,
+            max_installs: 1,
+            total_installs: 0,
+            _reserved: [0; 15],
+        }
+    }
+
+    fn default_allowlist_claim() -> AllowlistClaim {
+        AllowlistClaim {
+            wallet: Default::default(),
+            xnft: Default::default(),
+            claimed_ts: 0,
+            bump: 0,
+            _reserved: [0; 32],
+        }
+    }
+
+    fn default_category() -> Category {
+        Category {
+            id: 2,
+            bump: 0,
+            name: "Games".to_owned(),
             _reserved: [0; 32],
         }
     }
 
+    fn default_curator() -> Curator {
+        Curator {
+            authority: Default::default(),
+            created_ts: 0,
+            updated_ts: 0,
+            bump: 0,
+            name: "curator".to_owned(),
+            uri: "sample".to_owned(),
+            members: vec![Default::default()],
+            total_xnfts: 2,
+            _reserved: [0; 24],
+        }
+    }
+
+    fn default_delegate() -> Delegate {
+        Delegate {
+            xnft: Default::default(),
+            wallet: Default::default(),
+            owner: Default::default(),
+            permissions: 0b0101,
+            created_ts: 0,
+            updated_ts: 0,
+            bump: 0,
+            _reserved: [0; 32],
+        }
+    }
+
+    fn default_escrow() -> Escrow {
+        Escrow {
+            install: Default::default(),
+            xnft: Default::default(),
+            payer: Default::default(),
+            mint: None,
+            amount: 100,
+            refund_deadline: 0,
+            bump: 0,
+            edition: 3,
+            split_install_payments: false,
+            fee_basis_points: 250,
+            _reserved: [0; 21],
+        }
+    }
+
     fn default_install() -> Install {
         Install {
             authority: Default::default(),
             xnft: Default::default(),
             master_metadata: Default::default(),
             edition: 0,
-            _reserved: [0; 64],
+            expires_ts: None,
+            receipt_mint: None,
+            _reserved: [0; 22],
+        }
+    }
+
+    fn default_protocol_config() -> ProtocolConfig {
+        ProtocolConfig::new(Default::default(), Default::default(), 250, 0)
+    }
+
+    fn default_release() -> Release {
+        Release {
+            xnft: Default::default(),
+            version: 1,
+            publisher: Default::default(),
+            created_ts: 0,
+            content_hash: Some([171; 32]),
+            bump: 0,
+            uri: "sample".to_owned(),
+            _reserved: [0; 32],
         }
     }
 
@@ -226,6 +784,59 @@ mod tests {
             xnft: Default::default(),
             rating: 0,
             uri: "sample".to_owned(),
+            hidden: false,
+            _reserved: [0; 31],
+        }
+    }
+
+    fn default_review_reply() -> ReviewReply {
+        ReviewReply {
+            review: Default::default(),
+            xnft: Default::default(),
+            author: Default::default(),
+            created_ts: 0,
+            updated_ts: 0,
+            bump: 0,
+            uri: "sample".to_owned(),
+            _reserved: [0; 32],
+        }
+    }
+
+    fn default_update_proposal() -> UpdateProposal {
+        UpdateProposal {
+            xnft: Default::default(),
+            proposer: Default::default(),
+            curator: Default::default(),
+            status: ProposalStatus::Pending,
+            created_ts: 0,
+            reviewed_ts: None,
+            bump: 0,
+            updates: UpdateParams {
+                categories: None,
+                content_hash: None,
+                install_authority: None,
+                install_mint: None,
+                install_price: Some(100),
+                install_vault: None,
+                mint_install_receipts: None,
+                name: None,
+                refund_window: None,
+                split_install_payments: None,
+                subscription_period: None,
+                supply: None,
+                uri: Some("sample".to_owned()),
+            },
+            _reserved: [0; 32],
+        }
+    }
+
+    fn default_voucher_nonce() -> VoucherNonce {
+        VoucherNonce {
+            xnft: Default::default(),
+            wallet: Default::default(),
+            nonce: 0,
+            redeemed_ts: 0,
+            bump: 0,
             _reserved: [0; 32],
         }
     }
@@ -244,7 +855,7 @@ mod tests {
             uri: "sample".to_owned(),
             mint_seed_name: Some("test".to_owned()),
             kind: Kind::App,
-            tag: Tag::None,
+            _legacy_tag: 0,
             supply: Some(1),
             total_installs: 0,
             install_price: 0,
@@ -254,18 +865,202 @@ mod tests {
             num_ratings: 0,
             suspended: false,
             bump: [0],
-            _reserved0: [0; 64],
-            _reserved1: [0; 24],
-            _reserved2: [0; 9],
+            install_mint: None,
+            split_install_payments: false,
+            subscription_period: None,
+            refund_window: None,
+            mint_install_receipts: false,
+            rating_counts: [0; 5],
+            current_release: 0,
+            total_releases: 0,
+            content_hash: None,
+            allowlist_root: None,
+            install_gate: None,
+            suspension: None,
+            categories: 1 << 2 | 1 << 5,
+            _reserved: [0; 13],
         }
     }
 
     #[test]
-    fn access_debug() {
-        let acc = default_access();
-        let output = "Access {
-            wallet: 11111111111111111111111111111111,
+    fn access_debug() {
+        let acc = default_access();
+        let output = "Access {
+            wallet: 11111111111111111111111111111111,
+            xnft: 11111111111111111111111111111111,
+            bump: 0,
+            expires_ts: None,
+            max_installs: 1,
+            total_installs: 0
+        }"
+        .split_whitespace()
+        .join(" ");
+
+        assert_eq!(output, format!("{acc:?}"));
+    }
+
+    #[test]
+    fn access_serialization() {
+        let acc = default_access();
+        assert_ser_tokens(
+            &acc,
+            &[
+                Token::Struct {
+                    name: "Access",
+                    len: 6,
+                },
+                Token::Str("wallet"),
+                Token::Str("11111111111111111111111111111111"),
+                Token::Str("xnft"),
+                Token::Str("11111111111111111111111111111111"),
+                Token::Str("bump"),
+                Token::U8(0),
+                Token::Str("expiresTimestamp"),
+                Token::None,
+                Token::Str("maxInstalls"),
+                Token::U32(1),
+                Token::Str("totalInstalls"),
+                Token::U32(0),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn allowlist_claim_debug() {
+        let acc = default_allowlist_claim();
+        let output = "AllowlistClaim {
+            wallet: 11111111111111111111111111111111,
+            xnft: 11111111111111111111111111111111,
+            claimed_ts: 0,
+            bump: 0
+        }"
+        .split_whitespace()
+        .join(" ");
+
+        assert_eq!(output, format!("{acc:?}"));
+    }
+
+    #[test]
+    fn allowlist_claim_serialization() {
+        let acc = default_allowlist_claim();
+        assert_ser_tokens(
+            &acc,
+            &[
+                Token::Struct {
+                    name: "AllowlistClaim",
+                    len: 4,
+                },
+                Token::Str("wallet"),
+                Token::Str("11111111111111111111111111111111"),
+                Token::Str("xnft"),
+                Token::Str("11111111111111111111111111111111"),
+                Token::Str("claimedTimestamp"),
+                Token::I64(0),
+                Token::Str("bump"),
+                Token::U8(0),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn category_debug() {
+        let acc = default_category();
+        let output = "Category {
+            id: 2,
+            bump: 0,
+            name: \"Games\"
+        }"
+        .split_whitespace()
+        .join(" ");
+
+        assert_eq!(output, format!("{acc:?}"));
+    }
+
+    #[test]
+    fn category_serialization() {
+        let acc = default_category();
+        assert_ser_tokens(
+            &acc,
+            &[
+                Token::Struct {
+                    name: "Category",
+                    len: 3,
+                },
+                Token::Str("id"),
+                Token::U8(2),
+                Token::Str("bump"),
+                Token::U8(0),
+                Token::Str("name"),
+                Token::Str("Games"),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn curator_debug() {
+        let acc = default_curator();
+        let output = "Curator {
+            authority: 11111111111111111111111111111111,
+            created_ts: 0,
+            updated_ts: 0,
+            bump: 0,
+            name: \"curator\",
+            uri: \"sample\",
+            members: [11111111111111111111111111111111],
+            total_xnfts: 2
+        }"
+        .split_whitespace()
+        .join(" ");
+
+        assert_eq!(output, format!("{acc:?}"));
+    }
+
+    #[test]
+    fn curator_serialization() {
+        let acc = default_curator();
+        assert_ser_tokens(
+            &acc,
+            &[
+                Token::Struct {
+                    name: "Curator",
+                    len: 8,
+                },
+                Token::Str("authority"),
+                Token::Str("11111111111111111111111111111111"),
+                Token::Str("createdTimestamp"),
+                Token::I64(0),
+                Token::Str("updatedTimestamp"),
+                Token::I64(0),
+                Token::Str("bump"),
+                Token::U8(0),
+                Token::Str("name"),
+                Token::Str("curator"),
+                Token::Str("uri"),
+                Token::Str("sample"),
+                Token::Str("members"),
+                Token::Seq { len: Some(1) },
+                Token::Str("11111111111111111111111111111111"),
+                Token::SeqEnd,
+                Token::Str("totalXnfts"),
+                Token::U64(2),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn delegate_debug() {
+        let acc = default_delegate();
+        let output = "Delegate {
             xnft: 11111111111111111111111111111111,
+            wallet: 11111111111111111111111111111111,
+            owner: 11111111111111111111111111111111,
+            permissions: 0b0101,
+            created_ts: 0,
+            updated_ts: 0,
             bump: 0
         }"
         .split_whitespace()
@@ -275,19 +1070,27 @@ mod tests {
     }
 
     #[test]
-    fn access_serialization() {
-        let acc = default_access();
+    fn delegate_serialization() {
+        let acc = default_delegate();
         assert_ser_tokens(
             &acc,
             &[
                 Token::Struct {
-                    name: "Access",
-                    len: 3,
+                    name: "Delegate",
+                    len: 7,
                 },
+                Token::Str("xnft"),
+                Token::Str("11111111111111111111111111111111"),
                 Token::Str("wallet"),
                 Token::Str("11111111111111111111111111111111"),
-                Token::Str("xnft"),
+                Token::Str("owner"),
                 Token::Str("11111111111111111111111111111111"),
+                Token::Str("permissions"),
+                Token::U8(5),
+                Token::Str("createdTimestamp"),
+                Token::I64(0),
+                Token::Str("updatedTimestamp"),
+                Token::I64(0),
                 Token::Str("bump"),
                 Token::U8(0),
                 Token::StructEnd,
@@ -295,6 +1098,59 @@ mod tests {
         );
     }
 
+    #[test]
+    fn escrow_debug() {
+        let acc = default_escrow();
+        let output = "Escrow {
+            install: 11111111111111111111111111111111,
+            xnft: 11111111111111111111111111111111,
+            payer: 11111111111111111111111111111111,
+            mint: None,
+            amount: 100,
+            refund_deadline: 0,
+            edition: 3,
+            split_install_payments: false,
+            fee_basis_points: 250
+        }"
+        .split_whitespace()
+        .join(" ");
+
+        assert_eq!(output, format!("{acc:?}"));
+    }
+
+    #[test]
+    fn escrow_serialization() {
+        let acc = default_escrow();
+        assert_ser_tokens(
+            &acc,
+            &[
+                Token::Struct {
+                    name: "Escrow",
+                    len: 9,
+                },
+                Token::Str("install"),
+                Token::Str("11111111111111111111111111111111"),
+                Token::Str("xnft"),
+                Token::Str("11111111111111111111111111111111"),
+                Token::Str("payer"),
+                Token::Str("11111111111111111111111111111111"),
+                Token::Str("mint"),
+                Token::None,
+                Token::Str("amount"),
+                Token::U64(100),
+                Token::Str("refundDeadline"),
+                Token::I64(0),
+                Token::Str("edition"),
+                Token::U64(3),
+                Token::Str("splitInstallPayments"),
+                Token::Bool(false),
+                Token::Str("feeBasisPoints"),
+                Token::U16(250),
+                Token::StructEnd,
+            ],
+        );
+    }
+
     #[test]
     fn install_debug() {
         let acc = default_install();
@@ -302,7 +1158,9 @@ mod tests {
             authority: 11111111111111111111111111111111,
             xnft: 11111111111111111111111111111111,
             master_metadata: 11111111111111111111111111111111,
-            edition: 0
+            edition: 0,
+            expires_ts: None,
+            receipt_mint: None
         }"
         .split_whitespace()
         .join(" ");
@@ -318,7 +1176,7 @@ mod tests {
             &[
                 Token::Struct {
                     name: "Install",
-                    len: 4,
+                    len: 6,
                 },
                 Token::Str("authority"),
                 Token::Str("11111111111111111111111111111111"),
@@ -328,6 +1186,96 @@ mod tests {
                 Token::Str("11111111111111111111111111111111"),
                 Token::Str("edition"),
                 Token::U64(0),
+                Token::Str("expiresTs"),
+                Token::None,
+                Token::Str("receiptMint"),
+                Token::None,
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn protocol_config_debug() {
+        let acc = default_protocol_config();
+        let output = "ProtocolConfig {
+            admin: 11111111111111111111111111111111,
+            pending_admin: None,
+            treasury: 11111111111111111111111111111111,
+            fee_basis_points: 250,
+            paused: false
+        }"
+        .split_whitespace()
+        .join(" ");
+
+        assert_eq!(output, format!("{acc:?}"));
+    }
+
+    #[test]
+    fn protocol_config_serialization() {
+        let acc = default_protocol_config();
+        assert_ser_tokens(
+            &acc,
+            &[
+                Token::Struct {
+                    name: "ProtocolConfig",
+                    len: 5,
+                },
+                Token::Str("admin"),
+                Token::Str("11111111111111111111111111111111"),
+                Token::Str("pendingAdmin"),
+                Token::None,
+                Token::Str("treasury"),
+                Token::Str("11111111111111111111111111111111"),
+                Token::Str("feeBasisPoints"),
+                Token::U16(250),
+                Token::Str("paused"),
+                Token::Bool(false),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn release_debug() {
+        let acc = default_release();
+        let output = "Release {
+            xnft: 11111111111111111111111111111111,
+            version: 1,
+            publisher: 11111111111111111111111111111111,
+            created_ts: 0,
+            content_hash: Some(\"abababababababababababababababababababababababababababababababab\"),
+            uri: \"sample\"
+        }"
+        .split_whitespace()
+        .join(" ");
+
+        assert_eq!(output, format!("{acc:?}"));
+    }
+
+    #[test]
+    fn release_serialization() {
+        let acc = default_release();
+        assert_ser_tokens(
+            &acc,
+            &[
+                Token::Struct {
+                    name: "Release",
+                    len: 6,
+                },
+                Token::Str("xnft"),
+                Token::Str("11111111111111111111111111111111"),
+                Token::Str("version"),
+                Token::U32(1),
+                Token::Str("publisher"),
+                Token::Str("11111111111111111111111111111111"),
+                Token::Str("createdTimestamp"),
+                Token::I64(0),
+                Token::Str("contentHash"),
+                Token::Some,
+                Token::Str("abababababababababababababababababababababababababababababababab"),
+                Token::Str("uri"),
+                Token::Str("sample"),
                 Token::StructEnd,
             ],
         );
@@ -340,7 +1288,8 @@ mod tests {
             author: 11111111111111111111111111111111,
             xnft: 11111111111111111111111111111111,
             rating: 0,
-            uri: \"sample\"
+            uri: \"sample\",
+            hidden: false
         }"
         .split_whitespace()
         .join(" ");
@@ -356,7 +1305,7 @@ mod tests {
             &[
                 Token::Struct {
                     name: "Review",
-                    len: 4,
+                    len: 5,
                 },
                 Token::Str("author"),
                 Token::Str("11111111111111111111111111111111"),
@@ -366,6 +1315,189 @@ mod tests {
                 Token::U8(0),
                 Token::Str("uri"),
                 Token::Str("sample"),
+                Token::Str("hidden"),
+                Token::Bool(false),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn review_reply_debug() {
+        let acc = default_review_reply();
+        let output = "ReviewReply {
+            review: 11111111111111111111111111111111,
+            xnft: 11111111111111111111111111111111,
+            author: 11111111111111111111111111111111,
+            created_ts: 0,
+            updated_ts: 0,
+            uri: \"sample\"
+        }"
+        .split_whitespace()
+        .join(" ");
+
+        assert_eq!(output, format!("{acc:?}"));
+    }
+
+    #[test]
+    fn review_reply_serialization() {
+        let acc = default_review_reply();
+        assert_ser_tokens(
+            &acc,
+            &[
+                Token::Struct {
+                    name: "ReviewReply",
+                    len: 6,
+                },
+                Token::Str("review"),
+                Token::Str("11111111111111111111111111111111"),
+                Token::Str("xnft"),
+                Token::Str("11111111111111111111111111111111"),
+                Token::Str("author"),
+                Token::Str("11111111111111111111111111111111"),
+                Token::Str("createdTimestamp"),
+                Token::I64(0),
+                Token::Str("updatedTimestamp"),
+                Token::I64(0),
+                Token::Str("uri"),
+                Token::Str("sample"),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn update_proposal_debug() {
+        let acc = default_update_proposal();
+        let output = "UpdateProposal {
+            xnft: 11111111111111111111111111111111,
+            proposer: 11111111111111111111111111111111,
+            curator: 11111111111111111111111111111111,
+            status: Pending,
+            created_ts: 0,
+            reviewed_ts: None,
+            updates: UpdateParams {
+                categories: None,
+                content_hash: None,
+                install_authority: None,
+                install_mint: None,
+                install_price: Some(100),
+                install_vault: None,
+                mint_install_receipts: None,
+                name: None,
+                refund_window: None,
+                split_install_payments: None,
+                subscription_period: None,
+                supply: None,
+                uri: Some(\"sample\")
+            }
+        }"
+        .split_whitespace()
+        .join(" ");
+
+        assert_eq!(output, format!("{acc:?}"));
+    }
+
+    #[test]
+    fn update_proposal_serialization() {
+        let acc = default_update_proposal();
+        assert_ser_tokens(
+            &acc,
+            &[
+                Token::Struct {
+                    name: "UpdateProposal",
+                    len: 7,
+                },
+                Token::Str("xnft"),
+                Token::Str("11111111111111111111111111111111"),
+                Token::Str("proposer"),
+                Token::Str("11111111111111111111111111111111"),
+                Token::Str("curator"),
+                Token::Str("11111111111111111111111111111111"),
+                Token::Str("status"),
+                Token::UnitVariant {
+                    name: "ProposalStatus",
+                    variant: "Pending",
+                },
+                Token::Str("createdTimestamp"),
+                Token::I64(0),
+                Token::Str("reviewedTimestamp"),
+                Token::None,
+                Token::Str("updates"),
+                Token::Struct {
+                    name: "UpdateParams",
+                    len: 13,
+                },
+                Token::Str("categories"),
+                Token::None,
+                Token::Str("contentHash"),
+                Token::None,
+                Token::Str("installAuthority"),
+                Token::None,
+                Token::Str("installMint"),
+                Token::None,
+                Token::Str("installPrice"),
+                Token::Some,
+                Token::U64(100),
+                Token::Str("installVault"),
+                Token::None,
+                Token::Str("mintInstallReceipts"),
+                Token::None,
+                Token::Str("name"),
+                Token::None,
+                Token::Str("refundWindow"),
+                Token::None,
+                Token::Str("splitInstallPayments"),
+                Token::None,
+                Token::Str("subscriptionPeriod"),
+                Token::None,
+                Token::Str("supply"),
+                Token::None,
+                Token::Str("uri"),
+                Token::Some,
+                Token::Str("sample"),
+                Token::StructEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn voucher_nonce_debug() {
+        let acc = default_voucher_nonce();
+        let output = "VoucherNonce {
+            xnft: 11111111111111111111111111111111,
+            wallet: 11111111111111111111111111111111,
+            nonce: 0,
+            redeemed_ts: 0,
+            bump: 0
+        }"
+        .split_whitespace()
+        .join(" ");
+
+        assert_eq!(output, format!("{acc:?}"));
+    }
+
+    #[test]
+    fn voucher_nonce_serialization() {
+        let acc = default_voucher_nonce();
+        assert_ser_tokens(
+            &acc,
+            &[
+                Token::Struct {
+                    name: "VoucherNonce",
+                    len: 5,
+                },
+                Token::Str("xnft"),
+                Token::Str("11111111111111111111111111111111"),
+                Token::Str("wallet"),
+                Token::Str("11111111111111111111111111111111"),
+                Token::Str("nonce"),
+                Token::U64(0),
+                Token::Str("redeemedTimestamp"),
+                Token::I64(0),
+                Token::Str("bump"),
+                Token::U8(0),
                 Token::StructEnd,
             ],
         );
@@ -387,7 +1519,6 @@ mod tests {
             uri: \"sample\",
             mint_seed_name: Some(\"test\"),
             kind: App,
-            tag: None,
             supply: Some(1),
             total_installs: 0,
             install_price: 0,
@@ -396,7 +1527,20 @@ mod tests {
             total_rating: 0,
             num_ratings: 0,
             suspended: false,
-            bump: [0]
+            bump: [0],
+            install_mint: None,
+            split_install_payments: false,
+            subscription_period: None,
+            refund_window: None,
+            mint_install_receipts: false,
+            rating_counts: [0, 0, 0, 0, 0],
+            current_release: 0,
+            total_releases: 0,
+            content_hash: None,
+            allowlist_root: None,
+            install_gate: None,
+            suspension: None,
+            categories: [2, 5]
         }"
         .split_whitespace()
         .join(" ");
@@ -412,7 +1556,7 @@ mod tests {
             &[
                 Token::Struct {
                     name: "Xnft",
-                    len: 19,
+                    len: 31,
                 },
                 Token::Str("publisher"),
                 Token::Str("11111111111111111111111111111111"),
@@ -445,11 +1589,6 @@ mod tests {
                     name: "Kind",
                     variant: "App",
                 },
-                Token::Str("tag"),
-                Token::UnitVariant {
-                    name: "Tag",
-                    variant: "None",
-                },
                 Token::Str("supply"),
                 Token::Some,
                 Token::U64(1),
@@ -469,6 +1608,41 @@ mod tests {
                 Token::Bool(false),
                 Token::Str("bump"),
                 Token::U8(0),
+                Token::Str("installMint"),
+                Token::None,
+                Token::Str("splitInstallPayments"),
+                Token::Bool(false),
+                Token::Str("subscriptionPeriod"),
+                Token::None,
+                Token::Str("refundWindow"),
+                Token::None,
+                Token::Str("mintInstallReceipts"),
+                Token::Bool(false),
+                Token::Str("ratingCounts"),
+                Token::Tuple { len: 5 },
+                Token::U32(0),
+                Token::U32(0),
+                Token::U32(0),
+                Token::U32(0),
+                Token::U32(0),
+                Token::TupleEnd,
+                Token::Str("currentRelease"),
+                Token::U32(0),
+                Token::Str("totalReleases"),
+                Token::U32(0),
+                Token::Str("contentHash"),
+                Token::None,
+                Token::Str("allowlistRoot"),
+                Token::None,
+                Token::Str("installGate"),
+                Token::None,
+                Token::Str("suspension"),
+                Token::None,
+                Token::Str("categories"),
+                Token::Seq { len: Some(2) },
+                Token::U8(2),
+                Token::U8(5),
+                Token::SeqEnd,
                 Token::StructEnd,
             ],
         );
@@ -0,0 +1,82 @@
+// Copyright (C) 2023 Blue Coral, Inc.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use anchor_lang::prelude::*;
+
+use super::UpdateParams;
+
+#[account]
+pub struct UpdateProposal {
+    /// The pubkey of the xNFT that the updates are proposed for (32).
+    pub xnft: Pubkey,
+    /// The pubkey of the xNFT owner that submitted the proposal (32).
+    pub proposer: Pubkey,
+    /// The pubkey of the `Curator` program account that reviews the proposal (32).
+    pub curator: Pubkey,
+    /// The `ProposalStatus` enum variant of the curator's review (1).
+    pub status: ProposalStatus,
+    /// The unix timestamp of when the proposal was created (8).
+    pub created_ts: i64,
+    /// The optional unix timestamp of when the curator reviewed the proposal (9).
+    pub reviewed_ts: Option<i64>,
+    /// The bump nonce for the proposal's PDA (1).
+    pub bump: u8,
+    /// The xNFT update parameters being proposed (UpdateParams::MAX_LEN).
+    pub updates: UpdateParams,
+    /// Unused reserved byte space for future additive changes.
+    pub _reserved: [u8; 32],
+}
+
+impl UpdateProposal {
+    pub const LEN: usize = 8 + (32 * 3) + 1 + 8 + 9 + 1 + UpdateParams::MAX_LEN + 32;
+
+    pub fn try_new(
+        xnft: Pubkey,
+        proposer: Pubkey,
+        curator: Pubkey,
+        updates: UpdateParams,
+        bump: u8,
+    ) -> Result<Self> {
+        Ok(Self {
+            xnft,
+            proposer,
+            curator,
+            status: ProposalStatus::Pending,
+            created_ts: Clock::get()?.unix_timestamp,
+            reviewed_ts: None,
+            bump,
+            updates,
+            _reserved: [0; 32],
+        })
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub enum ProposalStatus {
+    Pending,
+    Approved,
+    Rejected,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn account_size_matches() {
+        assert_eq!(UpdateParams::MAX_LEN, 426);
+        assert_eq!(UpdateProposal::LEN, 581);
+    }
+}
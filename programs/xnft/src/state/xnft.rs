@@ -14,10 +14,11 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use anchor_lang::prelude::*;
-use mpl_token_metadata::state::{MAX_NAME_LENGTH, MAX_URI_LENGTH};
+use anchor_spl::token_interface::spl_token_2022::state::Account as TokenAccount;
+use mpl_token_metadata::state::{Metadata, MAX_NAME_LENGTH, MAX_URI_LENGTH};
 
 use super::CreateXnftParams;
-use crate::CustomError;
+use crate::{CustomError, MIN_RATING};
 
 #[account]
 pub struct Xnft {
@@ -39,8 +40,8 @@ pub struct Xnft {
     pub mint_seed_name: Option<String>,
     /// The `Kind` enum variant describing the type of xNFT (1).
     pub kind: Kind,
-    /// The `Tag` enum variant to assign the category of xNFT (1).
-    pub tag: Tag,
+    /// The variant index of the removed fixed `Tag` enum, until moved into `categories` (1).
+    pub _legacy_tag: u8,
     /// The optional finite supply of installations available for this xNFT (9).
     pub supply: Option<u64>,
     /// Total amount of install accounts that have been created for this xNFT (8).
@@ -59,10 +60,34 @@ pub struct Xnft {
     pub suspended: bool,
     /// The bump nonce for the xNFT's PDA (1).
     pub bump: [u8; 1],
+    /// The optional SPL token mint that install payments are denominated in instead of SOL (33).
+    pub install_mint: Option<Pubkey>,
+    /// Whether install payments are split across the metadata creators instead of the install vault (1).
+    pub split_install_payments: bool,
+    /// The optional length in seconds of each paid subscription period for installations (9).
+    pub subscription_period: Option<i64>,
+    /// The optional length in seconds after an install that its payment can be refunded (9).
+    pub refund_window: Option<i64>,
+    /// Whether each installation mints a non-transferable receipt token to the installer (1).
+    pub mint_install_receipts: bool,
+    /// The number of ratings given for each star from `MIN_RATING` to `MAX_RATING` (4 * 5).
+    pub rating_counts: [u32; 5],
+    /// The version number of the `Release` that the URI currently points to (4).
+    pub current_release: u32,
+    /// Total amount of release accounts that have been published for this xNFT (4).
+    pub total_releases: u32,
+    /// The optional SHA-256 digest of the bundle content at the URI (33).
+    pub content_hash: Option<[u8; 32]>,
+    /// The optional merkle root of the wallets allowed to install the xNFT (33).
+    pub allowlist_root: Option<[u8; 32]>,
+    /// The optional token holding requirement for public installs of the xNFT (1 + 41).
+    pub install_gate: Option<InstallGate>,
+    /// The optional record of the party that suspended the xNFT (1 + 42).
+    pub suspension: Option<Suspension>,
+    /// The bitset of the ids of the registered `Category` accounts assigned to the xNFT (8).
+    pub categories: u64,
     /// Unused reserved byte space for additive future changes.
-    pub _reserved0: [u8; 64],
-    pub _reserved1: [u8; 24],
-    pub _reserved2: [u8; 9],
+    pub _reserved: [u8; 13],
 }
 
 impl Xnft {
@@ -79,7 +104,20 @@ impl Xnft {
         + 4
         + 1
         + 1
-        + 97;
+        + 33
+        + 1
+        + 9
+        + 9
+        + 1
+        + (4 * 5)
+        + 4
+        + 4
+        + 33
+        + 33
+        + (1 + InstallGate::LEN)
+        + (1 + Suspension::LEN)
+        + 8
+        + 13;
 
     pub fn try_new(
         kind: Kind,
@@ -104,7 +142,7 @@ impl Xnft {
             uri: params.uri.clone(),
             mint_seed_name: seed_name,
             kind,
-            tag: params.tag.clone(),
+            _legacy_tag: 0,
             supply: params.supply,
             total_installs: 0,
             install_price: params.install_price,
@@ -114,12 +152,48 @@ impl Xnft {
             num_ratings: 0,
             suspended: false,
             bump: [bump],
-            _reserved0: [0; 64],
-            _reserved1: [0; 24],
-            _reserved2: [0; 9],
+            install_mint: params.install_mint,
+            split_install_payments: params.split_install_payments,
+            subscription_period: params.subscription_period,
+            refund_window: params.refund_window,
+            mint_install_receipts: params.mint_install_receipts,
+            rating_counts: [0; 5],
+            current_release: 0,
+            total_releases: 0,
+            content_hash: params.content_hash,
+            allowlist_root: None,
+            install_gate: None,
+            suspension: None,
+            categories: params.categories,
+            _reserved: [0; 13],
         })
     }
 
+    /// Adds a review rating to the cumulative rating and per-star counts.
+    pub fn add_rating(&mut self, rating: u8) {
+        self.total_rating += rating as u64;
+        self.num_ratings += 1;
+        if let Some(count) = self.rating_count_mut(rating) {
+            *count += 1;
+        }
+    }
+
+    /// Removes a review rating from the cumulative rating and per-star counts.
+    pub fn remove_rating(&mut self, rating: u8) {
+        self.total_rating -= rating as u64;
+        self.num_ratings -= 1;
+        // Ratings created before the per-star counts were tracked are
+        // not reflected in them, so the counts must not underflow.
+        if let Some(count) = self.rating_count_mut(rating) {
+            *count = count.saturating_sub(1);
+        }
+    }
+
+    fn rating_count_mut(&mut self, rating: u8) -> Option<&mut u32> {
+        let index = rating.checked_sub(MIN_RATING)?;
+        self.rating_counts.get_mut(index as usize)
+    }
+
     pub fn as_seeds(&self) -> [&[u8]; 3] {
         ["xnft".as_bytes(), self.master_mint.as_ref(), &self.bump]
     }
@@ -133,6 +207,103 @@ impl Xnft {
         Ok(())
     }
 
+    /// Verifies that the owner of an installation satisfies the install gate of
+    /// the xNFT, if there is one, with the provided token account and metadata.
+    pub fn verify_install_gate(
+        &self,
+        owner: &Pubkey,
+        token: Option<&TokenAccount>,
+        metadata: Option<&Metadata>,
+    ) -> Result<()> {
+        let Some(gate) = &self.install_gate else {
+            return Ok(());
+        };
+
+        let token = token.ok_or(error!(CustomError::InstallGateAccountMismatch))?;
+        require_keys_eq!(token.owner, *owner, CustomError::InstallGateAccountMismatch,);
+
+        match gate {
+            InstallGate::Collection { mint } => {
+                // The metadata must belong to the held token's mint and list
+                // the gating collection as verified.
+                let metadata = metadata.ok_or(error!(CustomError::InstallGateAccountMismatch))?;
+                require_keys_eq!(
+                    metadata.mint,
+                    token.mint,
+                    CustomError::InstallGateAccountMismatch,
+                );
+                require!(
+                    token.amount > 0
+                        && metadata
+                            .collection
+                            .as_ref()
+                            .is_some_and(|c| c.verified && c.key == *mint),
+                    CustomError::InstallGateNotMet,
+                );
+            }
+            InstallGate::Token { mint, amount } => {
+                require_keys_eq!(token.mint, *mint, CustomError::InstallGateAccountMismatch,);
+                require_gte!(token.amount, *amount, CustomError::InstallGateNotMet);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Suspends new installations of the xNFT on behalf of the authority acting
+    /// in the role, replacing any suspension placed by an equal or lower role.
+    pub fn suspend(
+        &mut self,
+        authority: Pubkey,
+        role: SuspensionRole,
+        reason: u8,
+        now: i64,
+    ) -> Result<()> {
+        self.verify_suspension_role(role)?;
+        self.suspended = true;
+        self.suspension = Some(Suspension {
+            authority,
+            role,
+            reason,
+            suspended_ts: now,
+        });
+        Ok(())
+    }
+
+    /// Lifts the suspension of the xNFT if it was placed by an equal or lower role.
+    pub fn unsuspend(&mut self, role: SuspensionRole) -> Result<()> {
+        self.verify_suspension_role(role)?;
+        self.suspended = false;
+        self.suspension = None;
+        Ok(())
+    }
+
+    fn verify_suspension_role(&self, role: SuspensionRole) -> Result<()> {
+        // Suspensions from before they were recorded could only have been
+        // placed by the owner of the xNFT.
+        let current = self
+            .suspension
+            .as_ref()
+            .map_or(SuspensionRole::Owner, |s| s.role);
+
+        require!(
+            !self.suspended || role >= current,
+            CustomError::SuspensionLocked,
+        );
+        Ok(())
+    }
+
+    /// Moves the removed fixed tag into the categories, where the variant index of
+    /// the tag is the id of its category, returning whether there was a tag to move.
+    pub fn migrate_legacy_tag(&mut self) -> bool {
+        if self._legacy_tag == 0 {
+            return false;
+        }
+        self.categories |= 1 << self._legacy_tag;
+        self._legacy_tag = 0;
+        true
+    }
+
     pub fn verify_supply(&self) -> anchor_lang::Result<()> {
         if let Some(supply) = self.supply {
             if supply == 0 || (supply > 0 && self.total_installs >= supply) {
@@ -151,36 +322,60 @@ pub struct CuratorStatus {
     pub verified: bool,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub enum InstallGate {
+    /// Requires holding an NFT that is a verified member of the collection.
+    Collection { mint: Pubkey },
+    /// Requires holding at least the amount of tokens of the SPL mint.
+    Token { mint: Pubkey, amount: u64 },
+}
+
+impl InstallGate {
+    pub const LEN: usize = 1 + 32 + 8;
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
 pub enum Kind {
     App,
     Collectible,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
-pub enum Tag {
-    None,
-    Defi,
-    Game,
-    Nfts,
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct Suspension {
+    /// The pubkey of the signer that suspended the xNFT (32).
+    pub authority: Pubkey,
+    /// The role that the signer suspended the xNFT as (1).
+    pub role: SuspensionRole,
+    /// The reason code for the suspension defined by the suspending party (1).
+    pub reason: u8,
+    /// The unix timestamp of when the xNFT was suspended (8).
+    pub suspended_ts: i64,
+}
+
+impl Suspension {
+    pub const LEN: usize = 32 + 1 + 1 + 8;
+}
+
+/// The parties that can suspend an xNFT, in ascending order of precedence.
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord,
+)]
+pub enum SuspensionRole {
+    Owner,
+    Curator,
+    Admin,
 }
 
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
     use anchor_lang::prelude::Pubkey;
     use std::str::FromStr;
 
     use super::*;
     use crate::CustomError;
 
-    #[test]
-    fn account_size_matches() {
-        assert_eq!(Xnft::LEN, 598);
-    }
-
-    #[test]
-    fn install_authority_checks() {
-        let mut x = Xnft {
+    pub(crate) fn default_xnft() -> Xnft {
+        Xnft {
             publisher: Default::default(),
             install_vault: Default::default(),
             master_metadata: Default::default(),
@@ -188,7 +383,7 @@ mod tests {
             install_authority: None,
             bump: Default::default(),
             kind: Kind::App,
-            tag: Tag::None,
+            _legacy_tag: 0,
             uri: Default::default(),
             mint_seed_name: None,
             total_installs: Default::default(),
@@ -200,10 +395,31 @@ mod tests {
             num_ratings: Default::default(),
             supply: None,
             curator: None,
-            _reserved0: [0; 64],
-            _reserved1: [0; 24],
-            _reserved2: [0; 9],
-        };
+            install_mint: None,
+            split_install_payments: false,
+            subscription_period: None,
+            refund_window: None,
+            mint_install_receipts: false,
+            rating_counts: [0; 5],
+            current_release: 0,
+            total_releases: 0,
+            content_hash: None,
+            allowlist_root: None,
+            install_gate: None,
+            suspension: None,
+            categories: 0,
+            _reserved: [0; 13],
+        }
+    }
+
+    #[test]
+    fn account_size_matches() {
+        assert_eq!(Xnft::LEN, 754);
+    }
+
+    #[test]
+    fn install_authority_checks() {
+        let mut x = default_xnft();
 
         assert!(x.verify_install_authority(&Pubkey::default()).is_ok());
 
@@ -221,32 +437,133 @@ mod tests {
     }
 
     #[test]
-    fn over_supplied_installed_checks() {
+    fn install_gate_checks() {
+        let mut x = default_xnft();
+
+        let mint = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let mut token = TokenAccount {
+            mint,
+            owner,
+            amount: 5,
+            ..Default::default()
+        };
+
+        assert!(x.verify_install_gate(&owner, None, None).is_ok());
+
+        x.install_gate = Some(InstallGate::Token { mint, amount: 10 });
+
+        assert_eq!(
+            x.verify_install_gate(&owner, None, None).unwrap_err(),
+            anchor_lang::error::Error::from(CustomError::InstallGateAccountMismatch),
+        );
+
+        assert_eq!(
+            x.verify_install_gate(&Pubkey::new_unique(), Some(&token), None)
+                .unwrap_err(),
+            anchor_lang::error::Error::from(CustomError::InstallGateAccountMismatch),
+        );
+
+        assert_eq!(
+            x.verify_install_gate(&owner, Some(&token), None)
+                .unwrap_err(),
+            anchor_lang::error::Error::from(CustomError::InstallGateNotMet),
+        );
+
+        token.amount = 10;
+        assert!(x.verify_install_gate(&owner, Some(&token), None).is_ok());
+
+        token.mint = Pubkey::new_unique();
+        assert_eq!(
+            x.verify_install_gate(&owner, Some(&token), None)
+                .unwrap_err(),
+            anchor_lang::error::Error::from(CustomError::InstallGateAccountMismatch),
+        );
+    }
+
+    #[test]
+    fn suspension_precedence() {
+        let mut x = default_xnft();
+
+        let owner = Pubkey::new_unique();
+        let locked = || anchor_lang::error::Error::from(CustomError::SuspensionLocked);
+
+        x.suspend(owner, SuspensionRole::Owner, 1, 10).unwrap();
+        assert!(x.suspended);
+        x.unsuspend(SuspensionRole::Owner).unwrap();
+        assert!(!x.suspended && x.suspension.is_none());
+
+        // A curator can replace the owner's suspension, which the owner can
+        // then neither replace nor lift.
+        x.suspend(owner, SuspensionRole::Owner, 1, 10).unwrap();
+        x.suspend(Pubkey::new_unique(), SuspensionRole::Curator, 2, 20)
+            .unwrap();
+        assert_eq!(
+            x.suspend(owner, SuspensionRole::Owner, 1, 30).unwrap_err(),
+            locked(),
+        );
+        assert_eq!(x.unsuspend(SuspensionRole::Owner).unwrap_err(), locked());
+
+        let admin = Pubkey::new_unique();
+        x.suspend(admin, SuspensionRole::Admin, 3, 40).unwrap();
+        assert_eq!(x.unsuspend(SuspensionRole::Curator).unwrap_err(), locked());
+
+        let suspension = x.suspension.as_ref().unwrap();
+        assert_eq!(suspension.authority, admin);
+        assert_eq!(suspension.reason, 3);
+        assert_eq!(suspension.suspended_ts, 40);
+
+        x.unsuspend(SuspensionRole::Admin).unwrap();
+        assert!(!x.suspended);
+
+        // Suspensions without a record are treated as placed by the owner.
+        x.suspended = true;
+        x.unsuspend(SuspensionRole::Owner).unwrap();
+    }
+
+    #[test]
+    fn rating_counts_track_reviews() {
+        let mut x = default_xnft();
+
+        x.add_rating(5);
+        x.add_rating(5);
+        x.add_rating(1);
+        assert_eq!(x.rating_counts, [1, 0, 0, 0, 2]);
+        assert_eq!(x.total_rating, 11);
+        assert_eq!(x.num_ratings, 3);
+
+        x.remove_rating(5);
+        x.add_rating(3);
+        assert_eq!(x.rating_counts, [1, 0, 1, 0, 1]);
+        assert_eq!(x.total_rating, 9);
+        assert_eq!(x.num_ratings, 3);
+
+        // Ratings that predate the counts leave them untouched.
+        x.remove_rating(2);
+        assert_eq!(x.rating_counts, [1, 0, 1, 0, 1]);
+        assert_eq!(x.num_ratings, 2);
+    }
+
+    #[test]
+    fn legacy_tag_migrates_to_category() {
         let mut x = Xnft {
-            publisher: Default::default(),
-            install_vault: Default::default(),
-            master_metadata: Default::default(),
-            master_mint: Default::default(),
-            install_authority: None,
-            bump: Default::default(),
-            kind: Kind::App,
-            tag: Tag::None,
-            uri: Default::default(),
-            mint_seed_name: None,
-            total_installs: Default::default(),
-            install_price: Default::default(),
-            created_ts: Default::default(),
-            updated_ts: Default::default(),
-            suspended: Default::default(),
-            total_rating: Default::default(),
-            num_ratings: Default::default(),
-            supply: None,
-            curator: None,
-            _reserved0: [0; 64],
-            _reserved1: [0; 24],
-            _reserved2: [0; 9],
+            _legacy_tag: 2,
+            categories: 1 << 5,
+            ..default_xnft()
         };
 
+        assert!(x.migrate_legacy_tag());
+        assert_eq!(x._legacy_tag, 0);
+        assert_eq!(x.categories, (1 << 2) | (1 << 5));
+
+        assert!(!x.migrate_legacy_tag());
+        assert_eq!(x.categories, (1 << 2) | (1 << 5));
+    }
+
+    #[test]
+    fn over_supplied_installed_checks() {
+        let mut x = default_xnft();
+
         assert!(x.verify_supply().is_ok());
 
         x.supply = Some(1);
@@ -0,0 +1,187 @@
+// Copyright (C) 2023 Blue Coral, Inc.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Verification of install vouchers that are signed off-chain by the install
+//! authority of an xNFT.
+//!
+//! Signatures are not verified by the program itself but by the native Ed25519
+//! program, which rejects the whole transaction if any signature in its
+//! instruction data is invalid. The program then only has to introspect the
+//! Ed25519 instruction preceding its own to check that it was the expected
+//! message that was signed by the expected key.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::ed25519_program;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+
+use crate::CustomError;
+
+const OFFSETS_START: usize = 2;
+const OFFSETS_LEN: usize = 14;
+const PUBKEY_LEN: usize = 32;
+#[cfg(any(test, feature = "cli"))]
+const SIGNATURE_LEN: usize = 64;
+
+/// The instruction index in the signature offsets that refers to the data of
+/// the Ed25519 instruction itself.
+const SELF_INSTRUCTION: u16 = u16::MAX;
+
+/// Verifies that the instruction immediately before the currently executing one
+/// is an Ed25519 program instruction for the signature of the message by the signer.
+pub fn verify_preceding(instructions: &AccountInfo, signer: &Pubkey, message: &[u8]) -> Result<()> {
+    let current = load_current_index_checked(instructions)? as usize;
+    require_gt!(current, 0, CustomError::InvalidVoucherSignature);
+
+    let ix = load_instruction_at_checked(current - 1, instructions)?;
+    verify(&ix, signer, message)
+}
+
+/// Verifies that the Ed25519 program instruction checks exactly one signature,
+/// which is of the message by the signer, with all of the signed data contained
+/// in the instruction itself.
+pub fn verify(ix: &Instruction, signer: &Pubkey, message: &[u8]) -> Result<()> {
+    let data = &ix.data;
+    require!(
+        ix.program_id == ed25519_program::ID
+            && data.len() >= OFFSETS_START + OFFSETS_LEN
+            && data[0] == 1,
+        CustomError::InvalidVoucherSignature,
+    );
+
+    let offset = |i: usize| {
+        let at = OFFSETS_START + i * 2;
+        u16::from_le_bytes([data[at], data[at + 1]])
+    };
+
+    // The signature, public key and message offsets are each followed by the
+    // index of the instruction containing the data they point to.
+    require!(
+        offset(1) == SELF_INSTRUCTION
+            && offset(3) == SELF_INSTRUCTION
+            && offset(6) == SELF_INSTRUCTION,
+        CustomError::InvalidVoucherSignature,
+    );
+
+    let pubkey_offset = offset(2) as usize;
+    let message_offset = offset(4) as usize;
+    let message_size = offset(5) as usize;
+
+    require!(
+        data.get(pubkey_offset..pubkey_offset + PUBKEY_LEN) == Some(signer.as_ref())
+            && data.get(message_offset..message_offset + message_size) == Some(message),
+        CustomError::InvalidVoucherSignature,
+    );
+
+    Ok(())
+}
+
+/// Builds the Ed25519 program instruction that verifies the signature of the
+/// message by the signer, to be placed before the instruction redeeming it.
+#[cfg(any(test, feature = "cli"))]
+pub fn new_ed25519_instruction(
+    signer: &Pubkey,
+    signature: &[u8; SIGNATURE_LEN],
+    message: &[u8],
+) -> Instruction {
+    let pubkey_offset = OFFSETS_START + OFFSETS_LEN;
+    let signature_offset = pubkey_offset + PUBKEY_LEN;
+    let message_offset = signature_offset + SIGNATURE_LEN;
+
+    let mut data = vec![1, 0];
+    for o in [
+        signature_offset as u16,
+        SELF_INSTRUCTION,
+        pubkey_offset as u16,
+        SELF_INSTRUCTION,
+        message_offset as u16,
+        message.len() as u16,
+        SELF_INSTRUCTION,
+    ] {
+        data.extend_from_slice(&o.to_le_bytes());
+    }
+    data.extend_from_slice(signer.as_ref());
+    data.extend_from_slice(signature);
+    data.extend_from_slice(message);
+
+    Instruction {
+        program_id: ed25519_program::ID,
+        accounts: vec![],
+        data,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn invalid() -> anchor_lang::error::Error {
+        anchor_lang::error::Error::from(CustomError::InvalidVoucherSignature)
+    }
+
+    #[test]
+    fn instruction_verifies_signer_and_message() {
+        let signer = Pubkey::new_unique();
+        let ix = new_ed25519_instruction(&signer, &[7; SIGNATURE_LEN], b"voucher");
+
+        assert!(verify(&ix, &signer, b"voucher").is_ok());
+        assert_eq!(
+            verify(&ix, &Pubkey::new_unique(), b"voucher").unwrap_err(),
+            invalid(),
+        );
+        assert_eq!(verify(&ix, &signer, b"vouches").unwrap_err(), invalid());
+        assert_eq!(verify(&ix, &signer, b"vouch").unwrap_err(), invalid());
+    }
+
+    #[test]
+    fn instruction_must_be_self_contained() {
+        let signer = Pubkey::new_unique();
+        let ix = new_ed25519_instruction(&signer, &[7; SIGNATURE_LEN], b"voucher");
+
+        // Point the public key at the data of another instruction, where the
+        // signature would have been checked against a different key.
+        let mut other = ix.clone();
+        other.data[OFFSETS_START + 6..OFFSETS_START + 8].copy_from_slice(&0u16.to_le_bytes());
+        assert_eq!(verify(&other, &signer, b"voucher").unwrap_err(), invalid());
+
+        let mut multiple = ix.clone();
+        multiple.data[0] = 2;
+        assert_eq!(
+            verify(&multiple, &signer, b"voucher").unwrap_err(),
+            invalid(),
+        );
+
+        let mut program = ix;
+        program.program_id = Pubkey::new_unique();
+        assert_eq!(
+            verify(&program, &signer, b"voucher").unwrap_err(),
+            invalid()
+        );
+    }
+
+    #[test]
+    fn truncated_data_is_rejected() {
+        let signer = Pubkey::new_unique();
+        let mut ix = new_ed25519_instruction(&signer, &[7; SIGNATURE_LEN], b"voucher");
+
+        ix.data.truncate(ix.data.len() - 1);
+        assert_eq!(verify(&ix, &signer, b"voucher").unwrap_err(), invalid());
+
+        ix.data.truncate(OFFSETS_START);
+        assert_eq!(verify(&ix, &signer, b"voucher").unwrap_err(), invalid());
+    }
+}